@@ -0,0 +1,103 @@
+/// Functions identically to [wrapped_list](crate::wrapped_list), but builds
+/// the array on the heap and returns a `Box<[W; N]>`.
+///
+/// The wrapped elements are pushed one at a time into preallocated heap
+/// storage, so very large lists don't build the whole array on the stack
+/// first, which can overflow it in debug builds. Each element is evaluated
+/// inside its own closure, so elements can't use `?` or `return`.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_boxed_array;
+///
+/// let boxed: Box<[Option<u32>; 3]> = wrapped_boxed_array![Some; 1, 2, 3];
+///
+/// assert_eq!(*boxed, [Some(1), Some(2), Some(3)]);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! wrapped_boxed_array {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::wrapped_boxed_array![@push [$(::core::stringify!($e)),*] $($crate::__apply_wrapper![$wrapper $e]),*]
+    };
+    [@push $names:tt $($value:expr),*] => {{
+        const __WRAPPED_LEN: usize = <[&str]>::len(&$names);
+        #[allow(unused_mut)]
+        let mut items = $crate::__private::Vec::with_capacity(__WRAPPED_LEN);
+        $(
+            $crate::__private::push_with(&mut items, || $value);
+        )*
+        $crate::__private::boxed_array::<_, __WRAPPED_LEN>(items)
+    }};
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_boxed_array [] $($rest)*]
+    };
+}
+
+/// Pushes the value returned by `f`, so that it is built in the frame of this
+/// call rather than in a separate stack slot of the caller for every element.
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+#[inline(never)]
+pub fn push_with<T>(items: &mut alloc::vec::Vec<T>, f: impl FnOnce() -> T) {
+    items.push(f());
+}
+
+/// Converts a vector with exactly `N` elements into a boxed array without
+/// copying the elements.
+#[doc(hidden)]
+#[cfg(feature = "alloc")]
+pub fn boxed_array<T, const N: usize>(items: alloc::vec::Vec<T>) -> alloc::boxed::Box<[T; N]> {
+    match items.into_boxed_slice().try_into() {
+        Ok(array) => array,
+        Err(_) => unreachable!("the list has exactly N elements"),
+    }
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use std::boxed::Box;
+    use std::string::String;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn boxed_array() {
+        let boxed: Box<[Wrapper; 2]> = wrapped_boxed_array![Wrapper; 1, 2];
+        assert_eq!(*boxed, [Wrapper(1), Wrapper(2)]);
+        assert_eq!(*wrapped_boxed_array![wrapper_macro! => 3], [Wrapper(3)]);
+        assert_eq!(*wrapped_boxed_array![derive Option<_>; 1], [Some(1)]);
+        assert_eq!(
+            *wrapped_boxed_array![<String as From<&str>>::from; "a"],
+            ["a"]
+        );
+        let empty: Box<[Wrapper; 0]> = wrapped_boxed_array![Wrapper;];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn large_boxed_array() {
+        fn page(byte: u8) -> [u8; 1 << 18] {
+            [byte; 1 << 18]
+        }
+
+        // 4 MiB in total, more than the default test thread stack can hold if
+        // the array were built there first.
+        let boxed =
+            wrapped_boxed_array![page; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+        assert_eq!(boxed.len(), 16);
+        assert!(boxed
+            .iter()
+            .zip(0..)
+            .all(|(page, i)| page.iter().all(|&b| b == i)));
+    }
+}
@@ -0,0 +1,121 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::WrappedList;
+
+/// Builds a list of wrapped values at runtime.
+///
+/// This is the runtime counterpart of [`wrapped_vec!`](crate::wrapped_vec), for when
+/// the elements are not known up front, such as values loaded from a config file.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use wrapped_list::WrappedListBuilder;
+///
+/// let boxed = WrappedListBuilder::new(Box::new)
+///     .push(1)
+///     .push(2)
+///     .extend([3, 4])
+///     .build_vec();
+///
+/// assert_eq!(boxed, [Box::new(1), Box::new(2), Box::new(3), Box::new(4)]);
+/// ```
+#[must_use]
+#[derive(Clone)]
+pub struct WrappedListBuilder<F, W> {
+    wrapper: F,
+    items: Vec<W>,
+}
+
+impl<F, W> WrappedListBuilder<F, W> {
+    /// Creates an empty builder that wraps every value with `wrapper`.
+    pub fn new<T>(wrapper: F) -> Self
+    where
+        F: FnMut(T) -> W,
+    {
+        WrappedListBuilder {
+            wrapper,
+            items: Vec::new(),
+        }
+    }
+
+    /// Wraps `value` and appends it.
+    pub fn push<T>(mut self, value: T) -> Self
+    where
+        F: FnMut(T) -> W,
+    {
+        self.items.push((self.wrapper)(value));
+        self
+    }
+
+    /// Wraps every value of `iter` and appends them.
+    pub fn extend<T, I>(mut self, iter: I) -> Self
+    where
+        F: FnMut(T) -> W,
+        I: IntoIterator<Item = T>,
+    {
+        self.items.extend(iter.into_iter().map(&mut self.wrapper));
+        self
+    }
+
+    /// Returns the number of values pushed so far.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no values have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns the wrapped values as a vector.
+    pub fn build_vec(self) -> Vec<W> {
+        self.items
+    }
+
+    /// Returns the wrapped values as a [`WrappedList`].
+    pub fn build(self) -> WrappedList<W> {
+        WrappedList::from(self.items)
+    }
+}
+
+impl<F, W: fmt::Debug> fmt::Debug for WrappedListBuilder<F, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WrappedListBuilder")
+            .field("items", &self.items)
+            .finish_non_exhaustive()
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::WrappedListBuilder;
+    use std::boxed::Box;
+    use std::string::{String, ToString};
+
+    #[test]
+    fn builder() {
+        let builder = WrappedListBuilder::new(Some).push(1).extend(2..4);
+        assert_eq!(builder.len(), 3);
+        assert_eq!(builder.build_vec(), wrapped_vec![Some; 1, 2, 3]);
+
+        let empty = WrappedListBuilder::new(Box::new).extend(core::iter::empty::<i32>());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn build_list() {
+        let mut count = 0;
+        let list = WrappedListBuilder::new(|s: &str| {
+            count += 1;
+            s.to_string()
+        })
+        .push("a")
+        .push("b")
+        .build();
+        assert_eq!(count, 2);
+        assert_eq!(list, wrapped_vec![#newtype String::from; "a", "b"]);
+    }
+}
@@ -0,0 +1,109 @@
+/// Creates an array of atomics of the given type, one per value, or `N` atomics
+/// set to the same value with `atomics_list![Type; value; N]`.
+///
+/// Atomics aren't `Copy`, so `[AtomicUsize::new(0); N]` doesn't compile. Both
+/// forms are `const`, so they can initialize `static` counter tables; the
+/// value of the repeat form must be a constant expression.
+///
+/// ```
+/// use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+/// use wrapped_list::atomics_list;
+///
+/// static HITS: [AtomicUsize; 4] = atomics_list![AtomicUsize; 0; 4];
+///
+/// HITS[2].fetch_add(1, Ordering::Relaxed);
+/// assert_eq!(HITS[2].load(Ordering::Relaxed), 1);
+///
+/// let flags = atomics_list![AtomicBool; true, false];
+/// assert!(flags[0].load(Ordering::Relaxed));
+/// ```
+#[macro_export]
+macro_rules! atomics_list {
+    [$atomic:path ; $value:expr ; $n:expr] => {
+        [const { <$atomic>::new($value) }; $n]
+    };
+    [$atomic:path ; $($e:expr),* $(,)?] => {
+        [$(<$atomic>::new($e)),*]
+    };
+}
+
+/// Creates an array of [`OnceLock`](std::sync::OnceLock)s, set to the given
+/// values or left empty where `_` is written.
+///
+/// Since `OnceLock` isn't `Copy`, `[OnceLock::new(); N]` doesn't compile, and
+/// pre-setting some of the cells otherwise takes a separate `set` call for each.
+/// A list made only of `_` is `const`, so it can initialize a `static` slot
+/// table.
+///
+/// Requires the `std` feature.
+///
+/// ```
+/// use std::sync::OnceLock;
+/// use wrapped_list::oncecell_list;
+///
+/// static SLOTS: [OnceLock<String>; 2] = oncecell_list![_, _];
+///
+/// let cells = oncecell_list![1, _, 3];
+///
+/// assert_eq!(cells[0].get(), Some(&1));
+/// assert_eq!(cells[1].get_or_init(|| 2), &2);
+/// assert_eq!(SLOTS[1].get_or_init(|| "ready".into()), "ready");
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! oncecell_list {
+    [@cells [$($cell:expr),*]] => {
+        [$($cell),*]
+    };
+    [@cells [$($cell:expr),*] _ $(, $($rest:tt)*)?] => {
+        $crate::oncecell_list![@cells [$($cell,)* $crate::__private::OnceLock::new()] $($($rest)*)?]
+    };
+    [@cells [$($cell:expr),*] $value:expr $(, $($rest:tt)*)?] => {
+        $crate::oncecell_list![@cells [$($cell,)* $crate::__private::OnceLock::from($value)] $($($rest)*)?]
+    };
+    [$($rest:tt)*] => {
+        $crate::oncecell_list![@cells [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use core::sync::atomic::{AtomicI32, AtomicU8, Ordering};
+    use std::string::String;
+    use std::sync::OnceLock;
+
+    const START: u8 = 7;
+
+    static COUNTERS: [AtomicU8; 3] = atomics_list![AtomicU8; START; 3];
+    static VALUES: [AtomicI32; 2] = atomics_list![core::sync::atomic::AtomicI32; -1, 1];
+
+    #[test]
+    fn atomics_list() {
+        COUNTERS[1].fetch_add(1, Ordering::Relaxed);
+        assert_eq!(COUNTERS[0].load(Ordering::Relaxed), 7);
+        assert_eq!(COUNTERS[1].load(Ordering::Relaxed), 8);
+        assert_eq!(VALUES[0].load(Ordering::Relaxed), -1);
+
+        let local = atomics_list![AtomicI32; 1 + 1, 3,];
+        assert_eq!(local[0].swap(5, Ordering::Relaxed), 2);
+        let empty: [AtomicI32; 0] = atomics_list![AtomicI32;];
+        assert!(empty.is_empty());
+    }
+
+    static SLOTS: [OnceLock<u32>; 3] = oncecell_list![_, _, _,];
+
+    #[test]
+    fn oncecell_list() {
+        let cells = oncecell_list![String::from("a"), _, String::new(),];
+        assert_eq!(cells[0].get().map(String::as_str), Some("a"));
+        assert_eq!(cells[1].get(), None);
+        assert_eq!(cells[2].set(String::from("c")), Err(String::from("c")));
+
+        assert_eq!(SLOTS[2].get_or_init(|| 3), &3);
+        assert_eq!(SLOTS[0].get(), None);
+
+        let empty: [OnceLock<u8>; 0] = oncecell_list![];
+        assert!(empty.is_empty());
+    }
+}
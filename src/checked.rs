@@ -0,0 +1,43 @@
+/// Creates an array of numeric constants of the given type, checking at
+/// compile time that every element fits.
+///
+/// Each element is evaluated in a `const` block, so an out-of-range literal
+/// such as `300` for `u8`, or an overflowing expression such as `255 + 1`,
+/// fails the build instead of being truncated by `as` or panicking in
+/// `try_into().unwrap()` at runtime.
+///
+/// ```
+/// use wrapped_list::checked_list;
+///
+/// const OPCODES: [u8; 3] = checked_list![u8; 0x00, 0x7f, 0xff];
+///
+/// assert_eq!(OPCODES[2], u8::MAX);
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::checked_list;
+///
+/// let bytes = checked_list![u8; 0, 17, 300];
+/// ```
+#[macro_export]
+macro_rules! checked_list {
+    [$t:ty; $($e:expr),* $(,)?] => {
+        [$(const {
+            #[deny(overflowing_literals)]
+            let value: $t = $e;
+            value
+        }),*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn checked_list() {
+        assert_eq!(checked_list![i8; -128, 127, 1 + 2,], [i8::MIN, i8::MAX, 3]);
+        assert_eq!(checked_list![u64; 1 << 40], [1_099_511_627_776]);
+        let empty: [u8; 0] = checked_list![u8;];
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use core::mem::{ManuallyDrop, MaybeUninit};
+use core::ptr;
+
+/// Array that is filled one fixed-size chunk at a time.
+///
+/// Used by [wrapped](crate::wrapped) to expand very large lists as a series of
+/// small array literals written straight into uninitialized storage. The
+/// compiler type checks these much faster than a single literal with tens of
+/// thousands of elements, and no temporary larger than a chunk is created.
+/// If a wrapper panics, the elements written so far are dropped.
+#[doc(hidden)]
+pub struct ChunkedArray<T, const N: usize> {
+    array: MaybeUninit<[T; N]>,
+    len: usize,
+}
+
+impl<T, const N: usize> ChunkedArray<T, N> {
+    pub fn new() -> Self {
+        ChunkedArray {
+            array: MaybeUninit::uninit(),
+            len: 0,
+        }
+    }
+
+    /// Appends `chunk` after the elements pushed so far.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chunk doesn't fit in the remaining space.
+    pub fn push<const C: usize>(&mut self, chunk: [T; C]) {
+        assert!(C <= N - self.len, "chunk overflows the array");
+        // SAFETY: `self.len + C <= N`, so the chunk is written to in-bounds,
+        // uninitialized elements, and `[T; C]` has the same alignment as `T`.
+        unsafe {
+            self.array
+                .as_mut_ptr()
+                .cast::<T>()
+                .add(self.len)
+                .cast::<[T; C]>()
+                .write(chunk);
+        }
+        self.len += C;
+    }
+
+    /// Returns the filled array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than `N` elements were pushed.
+    pub fn finish(self) -> [T; N] {
+        assert_eq!(self.len, N, "chunked array is not full");
+        let this = ManuallyDrop::new(self);
+        // SAFETY: all `N` elements were initialized, and `this` is never
+        // dropped, so they are moved out exactly once.
+        unsafe { this.array.assume_init_read() }
+    }
+}
+
+impl<T, const N: usize> Default for ChunkedArray<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ChunkedArray<T, N> {
+    fn drop(&mut self) {
+        let initialized =
+            ptr::slice_from_raw_parts_mut(self.array.as_mut_ptr().cast::<T>(), self.len);
+        // SAFETY: the first `self.len` elements are initialized and are not
+        // used again.
+        unsafe { ptr::drop_in_place(initialized) }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::ChunkedArray;
+    use std::panic::AssertUnwindSafe;
+    use std::rc::Rc;
+
+    #[test]
+    fn push_chunks() {
+        let mut array = ChunkedArray::<_, 5>::new();
+        array.push([1, 2]);
+        array.push([3, 4]);
+        array.push([5]);
+        assert_eq!(array.finish(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn drop_partial() {
+        let item = Rc::new(());
+        let mut array = ChunkedArray::<_, 3>::new();
+        array.push([item.clone(), item.clone()]);
+        drop(array);
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+
+    fn failing_wrapper() -> Rc<()> {
+        panic!("wrapper panicked")
+    }
+
+    #[test]
+    fn drop_on_panic() {
+        let item = Rc::new(());
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            let mut array = ChunkedArray::<_, 3>::new();
+            array.push([item.clone(), item.clone()]);
+            array.push([failing_wrapper()]);
+            array.finish()
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&item), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_overflow() {
+        let mut array = ChunkedArray::<_, 3>::new();
+        array.push([1, 2]);
+        array.push([3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn finish_partial() {
+        let mut array = ChunkedArray::<_, 3>::new();
+        array.push([1, 2]);
+        array.finish();
+    }
+}
@@ -0,0 +1,160 @@
+/// Wraps a list of argument names into clap [`Arg`](clap::Arg)s, usually with
+/// `Arg::new` or a function that configures the shared settings of similar
+/// flags.
+///
+/// Without a modifier this returns an array, ready for `Command::args`.
+/// Prefix the wrapper with `#on(command)` to chain one `.arg(...)` call per
+/// element onto `command` instead and return the resulting `Command`.
+/// Literal names are checked for duplicates at compile time, since clap
+/// rejects duplicate argument ids when the command is built.
+///
+/// Requires the `clap` feature.
+///
+/// ```
+/// use clap::{Arg, ArgAction, Command};
+/// use wrapped_list::wrapped_args;
+///
+/// fn flag(name: &'static str) -> Arg {
+///     Arg::new(name).long(name).action(ArgAction::SetTrue)
+/// }
+///
+/// let command = wrapped_args![#on(Command::new("app")) flag; "verbose", "quiet", "dry-run"];
+/// let matches = command.get_matches_from(["app", "--quiet"]);
+///
+/// assert!(matches.get_flag("quiet"));
+/// assert!(!matches.get_flag("verbose"));
+///
+/// let args = wrapped_args![Arg::new; "input", "output"];
+/// assert_eq!(args[1].get_id(), "output");
+/// ```
+///
+/// ```compile_fail
+/// use clap::Arg;
+/// use wrapped_list::wrapped_args;
+///
+/// let args = wrapped_args![Arg::new; "input", "input"];
+/// ```
+#[cfg(feature = "clap")]
+#[macro_export]
+macro_rules! wrapped_args {
+    [#on($command:expr) $($rest:tt)*] => {
+        $crate::__clap![arg ($command) [] $($rest)*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__clap![list () [] $($rest)*]
+    };
+}
+
+/// Functions identically to [wrapped_args](crate::wrapped_args), but for
+/// subcommands: the wrapper returns a [`Command`](clap::Command), such as
+/// `Command::new`, and `#on(command)` chains `.subcommand(...)` calls.
+///
+/// Requires the `clap` feature.
+///
+/// ```
+/// use clap::Command;
+/// use wrapped_list::wrapped_subcommands;
+///
+/// let command = wrapped_subcommands![#on(Command::new("git")) Command::new; "init", "clone"];
+/// let matches = command.get_matches_from(["git", "clone"]);
+///
+/// assert_eq!(matches.subcommand_name(), Some("clone"));
+/// ```
+#[cfg(feature = "clap")]
+#[macro_export]
+macro_rules! wrapped_subcommands {
+    [#on($command:expr) $($rest:tt)*] => {
+        $crate::__clap![subcommand ($command) [] $($rest)*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__clap![list () [] $($rest)*]
+    };
+}
+
+/// Collects the wrapper tokens of [wrapped_args](crate::wrapped_args) and
+/// [wrapped_subcommands](crate::wrapped_subcommands), then checks literal
+/// names for duplicates and emits the array or the chained calls.
+#[cfg(feature = "clap")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __clap {
+    [@emit list () [$($wrapper:tt)*] $($e:expr),*] => {
+        $crate::wrapped_list![$($wrapper)* ; $($e),*]
+    };
+    [@emit $method:ident ($command:expr) $wrapper:tt $($e:expr),*] => {
+        $command $(.$method($crate::__clap![@element $wrapper $e]))*
+    };
+    [@element [$($wrapper:tt)*] $e:expr] => {{
+        let [wrapped] = $crate::wrapped_list![$($wrapper)* ; $e];
+        wrapped
+    }};
+    [$kind:ident $command:tt [$($wrapper:tt)*] ; $($e:literal),* $(,)?] => {{
+        $crate::__unique_keys!($($e),*);
+        $crate::__clap![@emit $kind $command [$($wrapper)*] $($e),*]
+    }};
+    [$kind:ident $command:tt [$($wrapper:tt)*] ; $($e:expr),* $(,)?] => {
+        $crate::__clap![@emit $kind $command [$($wrapper)*] $($e),*]
+    };
+    [$kind:ident $command:tt [$($wrapper:tt)*] => $($rest:tt)*] => {
+        $crate::__clap![$kind $command [$($wrapper)*] ; $($rest)*]
+    };
+    [$kind:ident $command:tt [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by the elements")
+    };
+    [$kind:ident $command:tt [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::__clap![$kind $command [$($wrapper)* $next] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "clap"))]
+mod tests {
+    use clap::{Arg, ArgAction, Command};
+    use std::string::String;
+
+    struct Flags;
+
+    impl Flags {
+        fn counted(name: &'static str) -> Arg {
+            Arg::new(name)
+                .short(name.chars().next())
+                .action(ArgAction::Count)
+        }
+    }
+
+    macro_rules! option {
+        ($e:expr) => {
+            Arg::new($e).long($e)
+        };
+    }
+
+    #[test]
+    fn args() {
+        let command = wrapped_args![#on(Command::new("app")) <Flags>::counted; "verbose", "quiet"];
+        let matches = command.get_matches_from(["app", "-vv", "-q"]);
+        assert_eq!(matches.get_count("verbose"), 2);
+        assert_eq!(matches.get_count("quiet"), 1);
+
+        let name = "config";
+        let command = wrapped_args![#on(Command::new("app")) option! => name, "log"];
+        let matches = command.get_matches_from(["app", "--config", "a.toml"]);
+        assert_eq!(matches.get_one::<String>("config").unwrap(), "a.toml");
+
+        let args = wrapped_args![Arg::new; "a", "b"];
+        let command = Command::new("app").args(args);
+        assert_eq!(command.get_arguments().count(), 2);
+        assert_eq!(
+            wrapped_args![#on(Command::new("app")) Arg::new;].get_name(),
+            "app"
+        );
+    }
+
+    #[test]
+    fn subcommands() {
+        let about = |name: &'static str| Command::new(name).about(name);
+        let command = wrapped_subcommands![#on(Command::new("app")) about; "run", "test"];
+        assert_eq!(command.get_subcommands().count(), 2);
+        let [run, test] = wrapped_subcommands![Command::new; "run", "test"];
+        assert_eq!((run.get_name(), test.get_name()), ("run", "test"));
+    }
+}
@@ -0,0 +1,907 @@
+use alloc::vec::{self, Vec};
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::slice;
+#[cfg(feature = "std")]
+use core::{borrow::Borrow, hash::Hash};
+#[cfg(feature = "std")]
+use std::collections::{hash_map, HashMap};
+
+use crate::Wrap;
+
+/// A list of wrapped values, backed by a `Vec<W>`.
+///
+/// It dereferences to `[W]`, so it can be used like a slice, and adds helpers
+/// that are aware of the wrapping. `wrapped_vec!` builds one when given the
+/// `#newtype` modifier.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use wrapped_list::{wrapped_vec, WrappedList};
+///
+/// let mut list: WrappedList<Box<i32>> = wrapped_vec![#newtype Box::new; 1, 2];
+/// list.push_wrapped(3);
+///
+/// assert_eq!(list.len(), 3);
+/// assert_eq!(list.iter_inner().sum::<i32>(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WrappedList<W> {
+    items: Vec<W>,
+}
+
+impl<W> WrappedList<W> {
+    /// Creates an empty list.
+    pub const fn new() -> Self {
+        WrappedList { items: Vec::new() }
+    }
+
+    /// Wraps `value` with the [`Wrap`] implementation of `W` and appends it.
+    pub fn push_wrapped<T>(&mut self, value: T)
+    where
+        W: Wrap<T, Output = W>,
+    {
+        self.items.push(W::wrap(value));
+    }
+
+    /// Appends an already wrapped value.
+    pub fn push(&mut self, wrapped: W) {
+        self.items.push(wrapped);
+    }
+
+    /// Iterates over references to the values inside the wrappers.
+    pub fn iter_inner<'a, T: ?Sized + 'a>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = &'a T> + DoubleEndedIterator + 'a
+    where
+        W: AsRef<T>,
+    {
+        self.items.iter().map(AsRef::as_ref)
+    }
+
+    /// Iterates over references to the values inside the wrappers, for
+    /// wrappers that implement [`Deref`] but not [`AsRef`].
+    pub fn iter_deref(&self) -> impl ExactSizeIterator<Item = &W::Target> + DoubleEndedIterator
+    where
+        W: Deref,
+    {
+        self.items.iter().map(Deref::deref)
+    }
+
+    /// Returns the underlying vector.
+    pub fn into_vec(self) -> Vec<W> {
+        self.items
+    }
+}
+
+impl<W> Default for WrappedList<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<W> Deref for WrappedList<W> {
+    type Target = [W];
+
+    fn deref(&self) -> &[W] {
+        &self.items
+    }
+}
+
+impl<W> DerefMut for WrappedList<W> {
+    fn deref_mut(&mut self) -> &mut [W] {
+        &mut self.items
+    }
+}
+
+impl<W> From<Vec<W>> for WrappedList<W> {
+    fn from(items: Vec<W>) -> Self {
+        WrappedList { items }
+    }
+}
+
+impl<W, const N: usize> From<[W; N]> for WrappedList<W> {
+    fn from(items: [W; N]) -> Self {
+        WrappedList {
+            items: items.into(),
+        }
+    }
+}
+
+impl<W> From<WrappedList<W>> for Vec<W> {
+    fn from(list: WrappedList<W>) -> Self {
+        list.items
+    }
+}
+
+impl<W> FromIterator<W> for WrappedList<W> {
+    fn from_iter<I: IntoIterator<Item = W>>(iter: I) -> Self {
+        WrappedList {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<W> Extend<W> for WrappedList<W> {
+    fn extend<I: IntoIterator<Item = W>>(&mut self, iter: I) {
+        self.items.extend(iter);
+    }
+}
+
+impl<W> IntoIterator for WrappedList<W> {
+    type Item = W;
+    type IntoIter = vec::IntoIter<W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, W> IntoIterator for &'a WrappedList<W> {
+    type Item = &'a W;
+    type IntoIter = slice::Iter<'a, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+impl<'a, W> IntoIterator for &'a mut WrappedList<W> {
+    type Item = &'a mut W;
+    type IntoIter = slice::IterMut<'a, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter_mut()
+    }
+}
+
+/// Wraps every element of a collection built at runtime into a [`WrappedList`].
+///
+/// It is implemented for everything that can be iterated by value, which
+/// covers `Vec`, arrays, and iterators.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use wrapped_list::{IntoWrapped, WrappedList};
+///
+/// let names = vec!["a", "b"];
+/// let owned: WrappedList<String> = names.into_wrapped(String::from);
+///
+/// assert_eq!(owned.iter_inner::<str>().collect::<Vec<_>>(), ["a", "b"]);
+/// assert_eq!((1..=3).into_wrapped(Box::new).len(), 3);
+/// ```
+pub trait IntoWrapped: IntoIterator + Sized {
+    /// Wraps every element with `wrapper` and collects them into a list.
+    fn into_wrapped<W>(self, wrapper: impl FnMut(Self::Item) -> W) -> WrappedList<W> {
+        self.into_iter().map(wrapper).collect()
+    }
+}
+
+impl<I: IntoIterator> IntoWrapped for I {}
+
+/// A vector that wraps every value pushed into it, so code that accumulates
+/// values one at a time can't forget to wrap one.
+///
+/// The wrapper is either the [`Wrap`] implementation of `W`, with
+/// [`new`](WrappedVec::new), or a closure stored in the vector, with
+/// [`with_wrapper`](WrappedVec::with_wrapper). It dereferences to `[W]`.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use std::rc::Rc;
+/// use wrapped_list::WrappedVec;
+///
+/// let mut shared: WrappedVec<i32, Rc<i32>> = WrappedVec::new();
+/// shared.push(1);
+/// shared.extend([2, 3]);
+///
+/// let mut labels = WrappedVec::with_wrapper(|id: u32| format!("#{id}"));
+/// labels.push(7);
+///
+/// assert_eq!(shared, [Rc::new(1), Rc::new(2), Rc::new(3)]);
+/// assert_eq!(labels, ["#7"]);
+/// ```
+pub struct WrappedVec<T, W, F = fn(T) -> W> {
+    wrapper: F,
+    items: Vec<W>,
+    marker: PhantomData<fn(T)>,
+}
+
+impl<T, W: Wrap<T, Output = W>> WrappedVec<T, W> {
+    /// Creates an empty vector that wraps values with the [`Wrap`]
+    /// implementation of `W`.
+    pub fn new() -> Self {
+        Self::with_wrapper(W::wrap)
+    }
+}
+
+impl<T, W, F: FnMut(T) -> W> WrappedVec<T, W, F> {
+    /// Creates an empty vector that wraps values with `wrapper`.
+    pub fn with_wrapper(wrapper: F) -> Self {
+        WrappedVec {
+            wrapper,
+            items: Vec::new(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Wraps `value` and appends it.
+    pub fn push(&mut self, value: T) {
+        self.items.push((self.wrapper)(value));
+    }
+}
+
+impl<T, W, F> WrappedVec<T, W, F> {
+    /// Removes the last wrapped value and returns it.
+    pub fn pop(&mut self) -> Option<W> {
+        self.items.pop()
+    }
+
+    /// Iterates over references to the values inside the wrappers.
+    pub fn iter_inner<'a, U: ?Sized + 'a>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = &'a U> + DoubleEndedIterator + 'a
+    where
+        W: AsRef<U>,
+    {
+        self.items.iter().map(AsRef::as_ref)
+    }
+
+    /// Iterates over references to the values inside the wrappers, for
+    /// wrappers that implement [`Deref`] but not [`AsRef`].
+    pub fn iter_deref(&self) -> impl ExactSizeIterator<Item = &W::Target> + DoubleEndedIterator
+    where
+        W: Deref,
+    {
+        self.items.iter().map(Deref::deref)
+    }
+
+    /// Returns the wrapped values as a vector.
+    pub fn into_vec(self) -> Vec<W> {
+        self.items
+    }
+
+    /// Returns the wrapped values as a [`WrappedList`].
+    pub fn into_list(self) -> WrappedList<W> {
+        WrappedList::from(self.items)
+    }
+}
+
+impl<T, W: Wrap<T, Output = W>> Default for WrappedVec<T, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, W, F> Deref for WrappedVec<T, W, F> {
+    type Target = [W];
+
+    fn deref(&self) -> &[W] {
+        &self.items
+    }
+}
+
+impl<T, W, F> DerefMut for WrappedVec<T, W, F> {
+    fn deref_mut(&mut self) -> &mut [W] {
+        &mut self.items
+    }
+}
+
+impl<T, W: fmt::Debug, F> fmt::Debug for WrappedVec<T, W, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.items).finish()
+    }
+}
+
+impl<T, W: Clone, F: Clone> Clone for WrappedVec<T, W, F> {
+    fn clone(&self) -> Self {
+        WrappedVec {
+            wrapper: self.wrapper.clone(),
+            items: self.items.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T, W: PartialEq<U>, F, U> PartialEq<[U]> for WrappedVec<T, W, F> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.items[..] == other[..]
+    }
+}
+
+impl<T, W: PartialEq<U>, F, U, const N: usize> PartialEq<[U; N]> for WrappedVec<T, W, F> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        self.items[..] == other[..]
+    }
+}
+
+impl<T, W: PartialEq<U>, F, U> PartialEq<Vec<U>> for WrappedVec<T, W, F> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        self.items[..] == other[..]
+    }
+}
+
+impl<T, W, F: FnMut(T) -> W> Extend<T> for WrappedVec<T, W, F> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        self.items.extend(iter.into_iter().map(&mut self.wrapper));
+    }
+}
+
+impl<T, W, F> From<WrappedVec<T, W, F>> for Vec<W> {
+    fn from(vec: WrappedVec<T, W, F>) -> Self {
+        vec.items
+    }
+}
+
+impl<T, W, F> IntoIterator for WrappedVec<T, W, F> {
+    type Item = W;
+    type IntoIter = vec::IntoIter<W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T, W, F> IntoIterator for &'a WrappedVec<T, W, F> {
+    type Item = &'a W;
+    type IntoIter = slice::Iter<'a, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
+
+/// A map of wrapped values, backed by a `HashMap<K, W>`.
+///
+/// It dereferences to the underlying `HashMap`, and adds helpers that are aware
+/// of the wrapping. `wrapped_hashmap!` builds one when given the `#newtype`
+/// modifier.
+///
+/// Requires the `std` feature.
+///
+/// ```
+/// use wrapped_list::{wrapped_hashmap, WrappedMap};
+///
+/// let mut map: WrappedMap<&str, Box<i32>> = wrapped_hashmap![#newtype Box::new; "a" => 1];
+/// map.insert_wrapped("b", 2);
+///
+/// assert_eq!(map.get_inner("b"), Some(&2));
+/// assert_eq!(map.values_inner().sum::<i32>(), 3);
+/// ```
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct WrappedMap<K, W> {
+    entries: HashMap<K, W>,
+}
+
+#[cfg(feature = "std")]
+impl<K, W> WrappedMap<K, W> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        WrappedMap {
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Iterates over the keys and references to the values inside the wrappers.
+    pub fn iter_inner<'a, T: ?Sized + 'a>(
+        &'a self,
+    ) -> impl ExactSizeIterator<Item = (&'a K, &'a T)> + 'a
+    where
+        W: AsRef<T>,
+    {
+        self.entries.iter().map(|(k, w)| (k, w.as_ref()))
+    }
+
+    /// Iterates over references to the values inside the wrappers.
+    pub fn values_inner<'a, T: ?Sized + 'a>(&'a self) -> impl ExactSizeIterator<Item = &'a T> + 'a
+    where
+        W: AsRef<T>,
+    {
+        self.entries.values().map(AsRef::as_ref)
+    }
+
+    /// Iterates over references to the values inside the wrappers, for
+    /// wrappers that implement [`Deref`] but not [`AsRef`].
+    pub fn values_deref(&self) -> impl ExactSizeIterator<Item = &W::Target>
+    where
+        W: Deref,
+    {
+        self.entries.values().map(Deref::deref)
+    }
+
+    /// Returns the underlying map.
+    pub fn into_inner(self) -> HashMap<K, W> {
+        self.entries
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, W> WrappedMap<K, W> {
+    /// Returns a reference to the value inside the wrapper stored for `key`.
+    pub fn get_inner<Q, T>(&self, key: &Q) -> Option<&T>
+    where
+        K: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+        W: AsRef<T>,
+        T: ?Sized,
+    {
+        self.entries.get(key).map(AsRef::as_ref)
+    }
+
+    /// Wraps `value` with the [`Wrap`] implementation of `W` and inserts it,
+    /// returning the previous wrapped value for `key`, if any.
+    pub fn insert_wrapped<T>(&mut self, key: K, value: T) -> Option<W>
+    where
+        W: Wrap<T, Output = W>,
+    {
+        self.entries.insert(key, W::wrap(value))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, W: PartialEq> PartialEq for WrappedMap<K, W> {
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, W: Eq> Eq for WrappedMap<K, W> {}
+
+#[cfg(feature = "std")]
+impl<K, W> Default for WrappedMap<K, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, W> Deref for WrappedMap<K, W> {
+    type Target = HashMap<K, W>;
+
+    fn deref(&self) -> &HashMap<K, W> {
+        &self.entries
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, W> DerefMut for WrappedMap<K, W> {
+    fn deref_mut(&mut self) -> &mut HashMap<K, W> {
+        &mut self.entries
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, W> From<HashMap<K, W>> for WrappedMap<K, W> {
+    fn from(entries: HashMap<K, W>) -> Self {
+        WrappedMap { entries }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, W, const N: usize> From<[(K, W); N]> for WrappedMap<K, W> {
+    fn from(entries: [(K, W); N]) -> Self {
+        WrappedMap {
+            entries: entries.into(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, W> From<WrappedMap<K, W>> for HashMap<K, W> {
+    fn from(map: WrappedMap<K, W>) -> Self {
+        map.entries
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, W> FromIterator<(K, W)> for WrappedMap<K, W> {
+    fn from_iter<I: IntoIterator<Item = (K, W)>>(iter: I) -> Self {
+        WrappedMap {
+            entries: iter.into_iter().collect(),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: Eq + Hash, W> Extend<(K, W)> for WrappedMap<K, W> {
+    fn extend<I: IntoIterator<Item = (K, W)>>(&mut self, iter: I) {
+        self.entries.extend(iter);
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, W> IntoIterator for WrappedMap<K, W> {
+    type Item = (K, W);
+    type IntoIter = hash_map::IntoIter<K, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'a, K, W> IntoIterator for &'a WrappedMap<K, W> {
+    type Item = (&'a K, &'a W);
+    type IntoIter = hash_map::Iter<'a, K, W>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.iter()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<W: ::serde::Serialize> ::serde::Serialize for WrappedList<W> {
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.items.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, W: ::serde::Deserialize<'de>> ::serde::Deserialize<'de> for WrappedList<W> {
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::deserialize(deserializer).map(WrappedList::from)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<K, W> ::serde::Serialize for WrappedMap<K, W>
+where
+    K: ::serde::Serialize,
+    W: ::serde::Serialize,
+{
+    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.entries.serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "std"))]
+impl<'de, K, W> ::serde::Deserialize<'de> for WrappedMap<K, W>
+where
+    K: ::serde::Deserialize<'de> + Eq + Hash,
+    W: ::serde::Deserialize<'de>,
+{
+    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HashMap::deserialize(deserializer).map(WrappedMap::from)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<W: ::rkyv::Archive> ::rkyv::Archive for WrappedList<W> {
+    type Archived = ::rkyv::vec::ArchivedVec<W::Archived>;
+    type Resolver = ::rkyv::vec::VecResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: ::rkyv::Place<Self::Archived>) {
+        self.items.resolve(resolver, out);
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<W, S> ::rkyv::Serialize<S> for WrappedList<W>
+where
+    W: ::rkyv::Serialize<S>,
+    S: ::rkyv::rancor::Fallible + ::rkyv::ser::Allocator + ::rkyv::ser::Writer + ?Sized,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.items.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "rkyv")]
+impl<W, D> ::rkyv::Deserialize<WrappedList<W>, D> for ::rkyv::vec::ArchivedVec<W::Archived>
+where
+    W: ::rkyv::Archive,
+    D: ::rkyv::rancor::Fallible + ?Sized,
+    Self: ::rkyv::Deserialize<Vec<W>, D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<WrappedList<W>, D::Error> {
+        ::rkyv::Deserialize::<Vec<W>, D>::deserialize(self, deserializer).map(WrappedList::from)
+    }
+}
+
+#[cfg(all(feature = "rkyv", feature = "std"))]
+impl<K, W> ::rkyv::Archive for WrappedMap<K, W>
+where
+    K: ::rkyv::Archive + Eq + Hash,
+    K::Archived: Eq + Hash,
+    W: ::rkyv::Archive,
+{
+    type Archived = ::rkyv::collections::swiss_table::ArchivedHashMap<K::Archived, W::Archived>;
+    type Resolver = ::rkyv::collections::swiss_table::HashMapResolver;
+
+    fn resolve(&self, resolver: Self::Resolver, out: ::rkyv::Place<Self::Archived>) {
+        self.entries.resolve(resolver, out);
+    }
+}
+
+#[cfg(all(feature = "rkyv", feature = "std"))]
+impl<K, W, S> ::rkyv::Serialize<S> for WrappedMap<K, W>
+where
+    K: ::rkyv::Serialize<S> + Eq + Hash,
+    K::Archived: Eq + Hash,
+    W: ::rkyv::Serialize<S>,
+    S: ::rkyv::rancor::Fallible + ::rkyv::ser::Allocator + ::rkyv::ser::Writer + ?Sized,
+    S::Error: ::rkyv::rancor::Source,
+{
+    fn serialize(&self, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
+        self.entries.serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "rkyv", feature = "std"))]
+impl<K, W, D> ::rkyv::Deserialize<WrappedMap<K, W>, D>
+    for ::rkyv::collections::swiss_table::ArchivedHashMap<K::Archived, W::Archived>
+where
+    K: ::rkyv::Archive + Eq + Hash,
+    W: ::rkyv::Archive,
+    D: ::rkyv::rancor::Fallible + ?Sized,
+    Self: ::rkyv::Deserialize<HashMap<K, W>, D>,
+{
+    fn deserialize(&self, deserializer: &mut D) -> Result<WrappedMap<K, W>, D::Error> {
+        ::rkyv::Deserialize::<HashMap<K, W>, D>::deserialize(self, deserializer)
+            .map(WrappedMap::from)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<W: ::bincode::Encode> ::bincode::Encode for WrappedList<W> {
+    fn encode<E: ::bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), ::bincode::error::EncodeError> {
+        self.items.encode(encoder)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<C, W: ::bincode::Decode<C>> ::bincode::Decode<C> for WrappedList<W> {
+    fn decode<D: ::bincode::de::Decoder<Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, ::bincode::error::DecodeError> {
+        Vec::decode(decoder).map(WrappedList::from)
+    }
+}
+
+#[cfg(feature = "bincode")]
+impl<'de, C, W: ::bincode::BorrowDecode<'de, C>> ::bincode::BorrowDecode<'de, C>
+    for WrappedList<W>
+{
+    fn borrow_decode<D: ::bincode::de::BorrowDecoder<'de, Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, ::bincode::error::DecodeError> {
+        Vec::borrow_decode(decoder).map(WrappedList::from)
+    }
+}
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+impl<K: ::bincode::Encode, W: ::bincode::Encode> ::bincode::Encode for WrappedMap<K, W> {
+    fn encode<E: ::bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), ::bincode::error::EncodeError> {
+        self.entries.encode(encoder)
+    }
+}
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+impl<C, K, W> ::bincode::Decode<C> for WrappedMap<K, W>
+where
+    K: ::bincode::Decode<C> + Eq + Hash,
+    W: ::bincode::Decode<C>,
+{
+    fn decode<D: ::bincode::de::Decoder<Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, ::bincode::error::DecodeError> {
+        HashMap::decode(decoder).map(WrappedMap::from)
+    }
+}
+
+#[cfg(all(feature = "bincode", feature = "std"))]
+impl<'de, C, K, W> ::bincode::BorrowDecode<'de, C> for WrappedMap<K, W>
+where
+    K: ::bincode::BorrowDecode<'de, C> + Eq + Hash,
+    W: ::bincode::BorrowDecode<'de, C>,
+{
+    fn borrow_decode<D: ::bincode::de::BorrowDecoder<'de, Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, ::bincode::error::DecodeError> {
+        HashMap::borrow_decode(decoder).map(WrappedMap::from)
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::{IntoWrapped, WrappedList, WrappedVec};
+    use std::boxed::Box;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn wrapped_list() {
+        let mut list: WrappedList<Box<i32>> = wrapped_vec![#newtype Box::new; 1, 2];
+        list.push_wrapped(3);
+        list.push(Box::new(4));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list[0], Box::new(1));
+        assert_eq!(
+            list.iter_inner().copied().collect::<Vec<i32>>(),
+            [1, 2, 3, 4]
+        );
+        assert_eq!(Vec::from(list), wrapped_vec![Box::new; 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn conversions() {
+        let list = WrappedList::from(wrapped_list![Box::new; 1, 2]);
+        assert_eq!(list, wrapped_vec![Box::new; 1, 2].into());
+        assert_eq!(list.clone().into_vec(), [Box::new(1), Box::new(2)]);
+        let collected: WrappedList<Box<i32>> = list.into_iter().rev().collect();
+        assert_eq!(collected, WrappedList::from([Box::new(2), Box::new(1)]));
+        let strings: WrappedList<String> = WrappedList::from(wrapped_vec![String::from; "a", "b"]);
+        assert_eq!(strings.iter_inner::<str>().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn inner_views() {
+        use core::mem::ManuallyDrop;
+
+        let list = WrappedList::from(wrapped_list![ManuallyDrop::new; 1, 2]);
+        assert_eq!(list.iter_deref().rev().collect::<Vec<_>>(), [&2, &1]);
+
+        let mut strings: WrappedVec<&str, String, _> = WrappedVec::with_wrapper(String::from);
+        strings.extend(["a", "b"]);
+        assert_eq!(strings.iter_inner::<str>().collect::<Vec<_>>(), ["a", "b"]);
+        assert_eq!(strings.iter_deref().map(str::len).sum::<usize>(), 2);
+    }
+
+    #[test]
+    fn into_wrapped() {
+        let from_vec: WrappedList<Box<i32>> = std::vec![1, 2].into_wrapped(Box::new);
+        assert_eq!(from_vec, WrappedList::from(wrapped_list![Box::new; 1, 2]));
+        assert_eq!([1, 2].into_wrapped(Some).into_vec(), [Some(1), Some(2)]);
+        let doubled = (1..=3).map(|x| x * 2).into_wrapped(Some);
+        assert_eq!(doubled.into_vec(), wrapped_vec![Some; 2, 4, 6]);
+        assert!(Vec::<i32>::new().into_wrapped(Some).is_empty());
+    }
+
+    #[test]
+    fn wrapped_vec() {
+        let mut boxed: WrappedVec<i32, Box<i32>> = WrappedVec::default();
+        boxed.push(1);
+        boxed.extend([2, 3]);
+        assert_eq!(boxed.len(), 3);
+        assert_eq!(boxed.pop(), Some(Box::new(3)));
+        assert_eq!(boxed, wrapped_vec![Box::new; 1, 2]);
+        assert_eq!(
+            boxed.clone().into_list(),
+            wrapped_vec![#newtype Box::new; 1, 2]
+        );
+
+        let mut offset = 0;
+        let mut counted = WrappedVec::with_wrapper(|name: &str| {
+            offset += 1;
+            (offset, name)
+        });
+        counted.push("a");
+        counted.extend(["b", "c"]);
+        counted[0].1 = "z";
+        assert_eq!(Vec::from(counted), [(1, "z"), (2, "b"), (3, "c")]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn wrapped_map() {
+        use super::WrappedMap;
+        use crate::wrapped_hashmap;
+        use std::collections::HashMap;
+
+        let mut map: WrappedMap<&str, Box<i32>> =
+            wrapped_hashmap![#newtype Box::new; "a" => 1, "b" => 2];
+        assert_eq!(map.insert_wrapped("c", 3), None);
+        assert_eq!(map.insert_wrapped("a", 10), Some(Box::new(1)));
+        assert_eq!(map.get_inner("a"), Some(&10));
+        assert_eq!(map.get_inner::<_, i32>("z"), None);
+        assert_eq!(map.len(), 3);
+        assert_eq!(map.values_inner().sum::<i32>(), 15);
+        assert_eq!(map.values_deref().sum::<i32>(), 15);
+
+        let mut entries: Vec<(&str, i32)> = map.iter_inner().map(|(k, v)| (*k, *v)).collect();
+        entries.sort();
+        assert_eq!(entries, [("a", 10), ("b", 2), ("c", 3)]);
+
+        let inner: HashMap<&str, Box<i32>> = map.clone().into();
+        assert_eq!(WrappedMap::from(inner), map);
+    }
+
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn rkyv() {
+        use rkyv::rancor::Error;
+
+        let list = wrapped_vec![#newtype Some; 1u32, 2];
+        let bytes = rkyv::to_bytes::<Error>(&list).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<WrappedList<Option<u32>>>, Error>(&bytes);
+        assert_eq!(
+            archived.unwrap()[1].as_ref().map(|value| value.to_native()),
+            Some(2)
+        );
+        let decoded: WrappedList<Option<u32>> = rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+        assert_eq!(decoded, list);
+    }
+
+    #[cfg(all(feature = "rkyv", feature = "std"))]
+    #[test]
+    fn rkyv_map() {
+        use super::WrappedMap;
+        use crate::wrapped_hashmap;
+        use rkyv::rancor::Error;
+
+        let map: WrappedMap<u8, Option<u8>> = wrapped_hashmap![#newtype Some; 1 => 10, 2 => 20];
+        let bytes = rkyv::to_bytes::<Error>(&map).unwrap();
+        let archived = rkyv::access::<rkyv::Archived<WrappedMap<u8, Option<u8>>>, Error>(&bytes);
+        assert_eq!(archived.unwrap().get(&2).unwrap().as_ref(), Some(&20));
+        let decoded: WrappedMap<u8, Option<u8>> = rkyv::from_bytes::<_, Error>(&bytes).unwrap();
+        assert_eq!(decoded, map);
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn bincode() {
+        let config = bincode::config::standard();
+        let list = wrapped_vec![#newtype Some; 1u8, 2];
+        let bytes = bincode::encode_to_vec(&list, config).unwrap();
+        assert_eq!(bytes, [2, 1, 1, 1, 2]);
+        let (decoded, _): (WrappedList<Option<u8>>, _) =
+            bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, list);
+        let bytes = bincode::encode_to_vec(&["a"][..], config).unwrap();
+        let (borrowed, _): (WrappedList<&str>, _) =
+            bincode::borrow_decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(borrowed, WrappedList::from(["a"]));
+    }
+
+    #[cfg(all(feature = "bincode", feature = "std"))]
+    #[test]
+    fn bincode_map() {
+        use super::WrappedMap;
+        use crate::wrapped_hashmap;
+
+        let config = bincode::config::standard();
+        let map: WrappedMap<String, Box<u8>> =
+            wrapped_hashmap![#newtype Box::new; String::from("a") => 1];
+        let bytes = bincode::encode_to_vec(&map, config).unwrap();
+        let (decoded, _): (WrappedMap<String, Box<u8>>, _) =
+            bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, map);
+    }
+
+    #[cfg(all(feature = "serde", feature = "std"))]
+    #[test]
+    fn serde() {
+        use super::WrappedMap;
+        use crate::wrapped_hashmap;
+
+        let list = wrapped_vec![#newtype Box::new; 1, 2];
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2]");
+        assert_eq!(
+            serde_json::from_str::<WrappedList<Box<i32>>>(&json).unwrap(),
+            list
+        );
+
+        let map: WrappedMap<String, Option<i32>> =
+            wrapped_hashmap![#newtype Some; String::from("a") => 1];
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(json, r#"{"a":1}"#);
+        assert_eq!(
+            serde_json::from_str::<WrappedMap<String, Option<i32>>>(&json).unwrap(),
+            map
+        );
+    }
+}
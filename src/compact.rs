@@ -0,0 +1,56 @@
+/// Expands to a single `map` of the wrapper over an array of the raw elements,
+/// instead of one inlined wrapper call per element.
+///
+/// Used by the `#compact` modifier of [wrapped_list](crate::wrapped_list) and
+/// [wrapped_vec](crate::wrapped_vec).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compact {
+    [@wrap [list] $wrapper:tt $($e:expr),* $(,)?] => {
+        [$($e),*].map($crate::__apply_wrapper![@fn $wrapper])
+    };
+    [@wrap [vec] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::__private::Vec::from_iter(
+            ::core::iter::IntoIterator::into_iter([$($e),*]).map($crate::__apply_wrapper![@fn $wrapper]),
+        )
+    };
+    [$kind:ident; $($rest:tt)*] => {
+        $crate::__parse_wrapper![list __compact [$kind] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use core::num::Wrapping;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(u16);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn compact_list() {
+        assert_eq!(
+            wrapped_list![#compact Wrapper; 1, 2, 3],
+            wrapped_list![Wrapper; 1, 2, 3]
+        );
+        assert_eq!(wrapped_list![#compact wrapper_macro! => 4], [Wrapper(4)]);
+        assert_eq!(wrapped_list![#compact derive Wrapping<_>; 5], [Wrapping(5)]);
+        assert_eq!(wrapped_list![#compact <Option<u8>>::Some; 6], [Some(6)]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn compact_vec() {
+        assert_eq!(
+            wrapped_vec![#compact Wrapper; 1, 2],
+            [Wrapper(1), Wrapper(2)]
+        );
+        assert_eq!(wrapped_vec![#compact wrapper_macro!; 3], [Wrapper(3)]);
+    }
+}
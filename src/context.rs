@@ -0,0 +1,78 @@
+/// Calls a fallible wrapper on every element and attaches the given context to
+/// its error, stopping at the first failure.
+///
+/// Each element is written as `value => context`, and the result is an
+/// `anyhow::Result<Vec<_>>`. The wrapper may return a `Result` with any
+/// standard error type, or an `Option`.
+///
+/// Requires the `anyhow` feature.
+///
+/// ```
+/// use wrapped_list::context_list;
+///
+/// fn parse_port(input: &str) -> Result<u16, std::num::ParseIntError> {
+///     input.parse()
+/// }
+///
+/// let ports = context_list![parse_port; "80" => "http port", "443" => "https port"];
+/// assert_eq!(ports.unwrap(), [80, 443]);
+///
+/// let error = context_list![parse_port; "80" => "http port", "x" => "https port"].unwrap_err();
+/// assert_eq!(error.to_string(), "https port");
+/// ```
+#[cfg(feature = "anyhow")]
+#[macro_export]
+macro_rules! context_list {
+    [@wrap [] $wrapper:tt $($e:expr => $c:expr),* $(,)?] => {
+        $crate::wrapped_try_vec![@collect $(
+            $crate::__private::anyhow::Context::context($crate::__apply_wrapper![$wrapper $e], $c)
+        ),*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![map context_list [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "anyhow"))]
+mod tests {
+    use std::format;
+    use std::num::ParseIntError;
+    use std::string::ToString;
+
+    fn parse(input: &str) -> Result<i32, ParseIntError> {
+        input.parse()
+    }
+
+    macro_rules! first_char {
+        ($e:expr) => {
+            str::chars($e).next()
+        };
+    }
+
+    #[test]
+    fn context_list() {
+        assert_eq!(
+            context_list![parse; "1" => "first", "2" => "second"].unwrap(),
+            [1, 2]
+        );
+
+        let error =
+            context_list![parse; "1" => "first", "x" => "second", "y" => "third"].unwrap_err();
+        assert_eq!(error.to_string(), "second");
+        assert_eq!(
+            error.root_cause().to_string(),
+            "invalid digit found in string"
+        );
+
+        let name = "b";
+        let error =
+            context_list![first_char!; "a" => "a", "" => format!("reading {name}")].unwrap_err();
+        assert_eq!(error.to_string(), "reading b");
+
+        assert_eq!(
+            context_list![<i32 as core::str::FromStr>::from_str; "3" => "third"].unwrap(),
+            [3]
+        );
+    }
+}
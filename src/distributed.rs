@@ -0,0 +1,148 @@
+#[cfg(feature = "linkme")]
+use alloc::vec::Vec;
+
+/// Declares a registry that [`#[wrapped_submit]`](crate::wrapped_submit) items
+/// from any crate in the build can be added to, and that
+/// [wrapped_collect](crate::wrapped_collect) gathers into a single list.
+///
+/// The registry is a [linkme](https://docs.rs/linkme) distributed slice of
+/// functions returning the wrapped type. A type alias with the same name is
+/// declared next to it, so items can be submitted with nothing more than the
+/// path of the registry.
+///
+/// Requires the `linkme` feature.
+///
+/// ```
+/// use wrapped_list::{wrapped_collect, wrapped_distributed_slice, wrapped_submit};
+///
+/// trait Plugin: Sync {
+///     fn name(&self) -> &str;
+/// }
+///
+/// struct Named(&'static str);
+///
+/// impl Plugin for Named {
+///     fn name(&self) -> &str {
+///         self.0
+///     }
+/// }
+///
+/// wrapped_distributed_slice! {
+///     static PLUGINS: [Box<dyn Plugin>];
+/// }
+///
+/// #[wrapped_submit(PLUGINS, Box::new)]
+/// static AUDIT: Named = Named("audit");
+///
+/// #[wrapped_submit(PLUGINS, Box::new)]
+/// static CACHE: Named = Named("cache");
+///
+/// let mut names: Vec<_> = wrapped_collect!(PLUGINS).iter().map(|p| p.name().to_owned()).collect();
+/// names.sort();
+///
+/// assert_eq!(names, ["audit", "cache"]);
+/// assert_eq!(AUDIT.name(), "audit");
+/// ```
+#[cfg(feature = "linkme")]
+#[macro_export]
+macro_rules! wrapped_distributed_slice {
+    ($(#[$attr:meta])* $vis:vis static $name:ident : [$t:ty] $(;)?) => {
+        $(#[$attr])*
+        #[$crate::__private::linkme::distributed_slice]
+        #[linkme(crate = $crate::__private::linkme)]
+        $vis static $name: [fn() -> $t];
+
+        #[doc(hidden)]
+        #[allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
+        $vis type $name = fn() -> $t;
+    };
+}
+
+/// Gathers every item submitted to a
+/// [wrapped_distributed_slice](crate::wrapped_distributed_slice) registry into
+/// a `Vec`, wrapping each one.
+///
+/// Items are returned in the order the linker placed them, which is not
+/// guaranteed to follow the order they were declared in; sort the list if the
+/// order matters. Every call builds fresh values, so gather the list once at
+/// startup and keep it if wrapping is expensive.
+///
+/// Requires the `linkme` feature.
+///
+/// ```
+/// use wrapped_list::{wrapped_collect, wrapped_distributed_slice, wrapped_submit};
+///
+/// wrapped_distributed_slice! {
+///     pub static LIMITS: [Option<u32>];
+/// }
+///
+/// #[wrapped_submit(LIMITS, Some)]
+/// static UPLOAD_LIMIT: u32 = 1 << 20;
+///
+/// assert_eq!(wrapped_collect!(LIMITS), [Some(1 << 20)]);
+/// ```
+#[cfg(feature = "linkme")]
+#[macro_export]
+macro_rules! wrapped_collect {
+    ($registry:path $(,)?) => {
+        $crate::__private::collect_submitted(&$registry)
+    };
+}
+
+/// Calls every entry of a distributed registry, for
+/// [wrapped_collect](crate::wrapped_collect).
+#[cfg(feature = "linkme")]
+pub fn collect_submitted<W>(entries: &[fn() -> W]) -> Vec<W> {
+    entries.iter().map(|entry| entry()).collect()
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "linkme"))]
+mod tests {
+    use crate::wrapped_submit;
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    mod registry {
+        use std::string::String;
+
+        wrapped_distributed_slice! {
+            /// Names of the loaded modules.
+            pub static NAMES: [String];
+        }
+    }
+
+    wrapped_distributed_slice! {
+        static SIZES: [Vec<u8>];
+    }
+
+    wrapped_distributed_slice! {
+        static EMPTY: [i32];
+    }
+
+    #[wrapped_submit(registry::NAMES, ToString::to_string)]
+    static CORE: &str = "core";
+
+    #[wrapped_submit(self::registry::NAMES, |name: &str| name.to_uppercase())]
+    static NET: &str = "net";
+
+    #[wrapped_submit(SIZES, Vec::from)]
+    static HEADER: [u8; 2] = [1, 2];
+
+    #[test]
+    fn distributed() {
+        let mut names = wrapped_collect!(registry::NAMES);
+        names.sort();
+        assert_eq!(names, ["NET", "core"]);
+        assert_eq!((CORE, NET), ("core", "net"));
+
+        let sizes: Vec<_> = wrapped_collect!(SIZES)
+            .into_iter()
+            .map(|size| size.len())
+            .collect();
+        assert_eq!(sizes, [2]);
+        assert_eq!(HEADER, [1, 2]);
+
+        assert!(wrapped_collect!(EMPTY).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+/// Wraps every variant of an enum deriving strum's
+/// [`EnumIter`](strum::EnumIter) and [`EnumCount`](strum::EnumCount), in
+/// declaration order.
+///
+/// The variants come from `IntoEnumIterator::iter`, so the list stays in sync
+/// with the enum definition, and the length of the returned array is the
+/// `EnumCount::COUNT` constant. Variants with fields are listed with their
+/// fields set to `Default::default()`, as `EnumIter` does.
+///
+/// Requires the `strum` feature.
+///
+/// ```
+/// use strum::{EnumCount, EnumIter};
+/// use wrapped_list::wrapped_all_variants;
+///
+/// #[derive(Debug, PartialEq, Eq, EnumIter, EnumCount)]
+/// enum Suit {
+///     Hearts,
+///     Spades,
+/// }
+///
+/// let boxed: [Box<Suit>; Suit::COUNT] = wrapped_all_variants![Box::new; Suit];
+///
+/// assert_eq!(boxed, [Box::new(Suit::Hearts), Box::new(Suit::Spades)]);
+///
+/// // `=>` can be used in place of `;`.
+/// let boxed = wrapped_all_variants![Box::new => Suit];
+///
+/// assert_eq!(boxed, [Box::new(Suit::Hearts), Box::new(Suit::Spades)]);
+/// ```
+#[cfg(feature = "strum")]
+#[macro_export]
+macro_rules! wrapped_all_variants {
+    [@wrap [] $wrapper:tt $enum:ty] => {
+        $crate::__private::all_variants::<$enum, { <$enum as $crate::__private::strum::EnumCount>::COUNT }>()
+            .map($crate::__apply_wrapper![@fn $wrapper])
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_all_variants [] $($rest)*]
+    };
+}
+
+/// Collects the variants returned by `IntoEnumIterator::iter` into an array,
+/// for [wrapped_all_variants](crate::wrapped_all_variants).
+#[cfg(feature = "strum")]
+pub fn all_variants<E: strum::IntoEnumIterator, const N: usize>() -> [E; N] {
+    let mut variants = E::iter();
+    core::array::from_fn(|_| {
+        variants
+            .next()
+            .expect("`EnumCount::COUNT` is larger than the number of variants")
+    })
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "strum"))]
+mod tests {
+    use std::boxed::Box;
+    use strum::{EnumCount, EnumIter};
+
+    #[derive(EnumIter, EnumCount, PartialEq, Eq, Debug, Clone, Copy)]
+    enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+
+    #[derive(EnumIter, EnumCount, PartialEq, Eq, Debug)]
+    enum Shape {
+        Point,
+        Circle(u32),
+    }
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(Direction);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn all_variants() {
+        use Direction::*;
+
+        assert_eq!(
+            wrapped_all_variants![Box::new; Direction],
+            [North, East, South, West].map(Box::new)
+        );
+        assert_eq!(
+            wrapped_all_variants![wrapper_macro!; Direction],
+            [Wrapper(North), Wrapper(East), Wrapper(South), Wrapper(West)]
+        );
+        assert_eq!(
+            wrapped_all_variants![Some; Shape],
+            [Some(Shape::Point), Some(Shape::Circle(0))]
+        );
+        assert_eq!(
+            wrapped_all_variants![Wrapper => Direction],
+            [Wrapper(North), Wrapper(East), Wrapper(South), Wrapper(West)]
+        );
+        assert_eq!(
+            wrapped_all_variants![wrapper_macro! => Direction],
+            [Wrapper(North), Wrapper(East), Wrapper(South), Wrapper(West)]
+        );
+    }
+}
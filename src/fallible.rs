@@ -0,0 +1,85 @@
+use alloc::vec::Vec;
+
+/// Applies a fallible wrapper to every element and splits the results into
+/// the wrapped values and the errors, both in element order.
+///
+/// Takes the same wrapper forms as [wrapped_try_vec](crate::wrapped_try_vec),
+/// including `derive` for [`TryWrap`](crate::TryWrap) types.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_partition;
+///
+/// let (ports, errors) = wrapped_partition![str::parse::<u16>; "80", "http", "443"];
+///
+/// assert_eq!(ports, [80, 443]);
+/// assert_eq!(errors.len(), 1);
+/// ```
+#[macro_export]
+macro_rules! wrapped_partition {
+    [derive $wrapper:ty ; $($e:expr),* $(,)?] => {
+        $crate::__private::partition([$(<$wrapper as $crate::TryWrap<_>>::try_wrap($e)),*])
+    };
+    [derive $wrapper:ty => $($rest:tt)*] => {
+        $crate::wrapped_partition![derive $wrapper ; $($rest)*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__private::partition($crate::wrapped_list![$($rest)*])
+    };
+}
+
+/// Splits the results of the `#all` mode of
+/// [wrapped_try_vec](crate::wrapped_try_vec) into the wrapped values, or every
+/// error paired with the index of the element that caused it.
+pub fn collect_all<T, E, const N: usize>(
+    results: [Result<T, E>; N],
+) -> Result<Vec<T>, Vec<(usize, E)>> {
+    let mut values = Vec::with_capacity(N);
+    let mut errors = Vec::new();
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push((index, error)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(values)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Splits the results of [wrapped_partition](crate::wrapped_partition).
+pub fn partition<T, E, const N: usize>(results: [Result<T, E>; N]) -> (Vec<T>, Vec<E>) {
+    let mut values = Vec::new();
+    let mut errors = Vec::new();
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => errors.push(error),
+        }
+    }
+    (values, errors)
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use core::num::NonZeroU8;
+    use std::vec::Vec;
+
+    #[test]
+    fn partition() {
+        let (values, errors) = wrapped_partition![NonZeroU8::try_from => 1u8, 0, 2];
+        assert_eq!(values, [NonZeroU8::MIN, NonZeroU8::new(2).unwrap()]);
+        assert_eq!(errors.len(), 1);
+
+        let (values, errors) = wrapped_partition![derive NonZeroU8; 0, 3];
+        assert_eq!(values, [NonZeroU8::new(3).unwrap()]);
+        assert_eq!(errors.len(), 1);
+
+        let (values, errors): (Vec<u8>, Vec<()>) = wrapped_partition![Ok;];
+        assert!(values.is_empty() && errors.is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use alloc::vec::Vec;
+use core::any::Any;
+
+/// Structs whose fields can be listed by name and wrapped.
+///
+/// With the `proc-macro` feature this can be derived. Fields of tuple structs
+/// are named by their index. See [wrapped_fields] for the macro form.
+pub trait Fields {
+    /// Returns every field name paired with the wrapped field, in declaration
+    /// order.
+    fn wrapped_fields<W>(&self, wrapper: impl FnMut(&dyn Any) -> W) -> Vec<(&'static str, W)>;
+}
+
+/// Lists every field of a struct implementing [`Fields`] with its name,
+/// wrapped with a function or macro taking `&dyn Any`.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use std::any::Any;
+/// use wrapped_list::{wrapped_fields, Fields};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// // Or `#[derive(Fields)]` with the `proc-macro` feature.
+/// impl Fields for Point {
+///     fn wrapped_fields<W>(&self, mut wrapper: impl FnMut(&dyn Any) -> W) -> Vec<(&'static str, W)> {
+///         vec![("x", wrapper(&self.x)), ("y", wrapper(&self.y))]
+///     }
+/// }
+///
+/// fn as_i32(field: &dyn Any) -> Option<i32> {
+///     field.downcast_ref().copied()
+/// }
+///
+/// let fields = wrapped_fields![as_i32; Point { x: 1, y: 2 }];
+///
+/// assert_eq!(fields, [("x", Some(1)), ("y", Some(2))]);
+/// ```
+#[macro_export]
+macro_rules! wrapped_fields {
+    [$wrapper:path ; $value:expr] => {
+        $crate::Fields::wrapped_fields(&$value, $wrapper)
+    };
+    [$wrapper:ident! ; $value:expr] => {
+        $crate::Fields::wrapped_fields(&$value, |field| $wrapper!(field))
+    };
+    [$($wrapper:ident)::+ ! ; $value:expr] => {{
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_fields![__wrapper!; $value]
+    }};
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "proc-macro"))]
+mod tests {
+    use crate::Fields;
+    use std::any::Any;
+    use std::string::{String, ToString};
+
+    #[derive(Fields)]
+    struct Config {
+        name: &'static str,
+        retries: u32,
+    }
+
+    #[derive(Fields)]
+    struct Pair(u32, &'static str);
+
+    fn describe(field: &dyn Any) -> String {
+        if let Some(value) = field.downcast_ref::<u32>() {
+            value.to_string()
+        } else if let Some(value) = field.downcast_ref::<&str>() {
+            value.to_string()
+        } else {
+            "?".to_string()
+        }
+    }
+
+    macro_rules! is_u32 {
+        ($e:expr) => {
+            $e.is::<u32>()
+        };
+    }
+
+    #[test]
+    fn derive_fields() {
+        let config = Config {
+            name: "server",
+            retries: 3,
+        };
+        assert_eq!(
+            wrapped_fields![describe; config],
+            [("name", "server".to_string()), ("retries", "3".to_string())]
+        );
+        assert_eq!(
+            wrapped_fields![is_u32!; Pair(1, "a")],
+            [("0", true), ("1", false)]
+        );
+    }
+}
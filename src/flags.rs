@@ -0,0 +1,148 @@
+/// Wraps every element and combines the results with `|`, producing a single
+/// flags value.
+///
+/// This works with any type implementing [`BitOr`](core::ops::BitOr), such as
+/// `bitflags` types or plain integers. At least one element is required.
+///
+/// Flag constants of a type can also be combined directly by listing them in
+/// braces after the type.
+///
+/// ```
+/// use wrapped_list::wrapped_flags;
+///
+/// #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// struct Flags(u8);
+///
+/// impl Flags {
+///     const READ: Flags = Flags(0x1);
+///     const WRITE: Flags = Flags(0x2);
+///
+///     fn from_bits_truncate(bits: u8) -> Flags {
+///         Flags(bits & 0x3)
+///     }
+/// }
+///
+/// impl std::ops::BitOr for Flags {
+///     type Output = Flags;
+///
+///     fn bitor(self, rhs: Flags) -> Flags {
+///         Flags(self.0 | rhs.0)
+///     }
+/// }
+///
+/// assert_eq!(wrapped_flags![Flags::from_bits_truncate; 0x1, 0x6], Flags(0x3));
+/// assert_eq!(wrapped_flags![Flags::{READ, WRITE}], Flags(0x3));
+/// ```
+#[macro_export]
+macro_rules! wrapped_flags {
+    [$($ty:ident)::+ :: { $($flag:ident),+ $(,)? }] => {{
+        type __Flags = $($ty)::+;
+        ($(__Flags::$flag)|+)
+    }};
+    [@wrap [] $wrapper:tt $($e:expr),+ $(,)?] => {
+        ($($crate::__apply_wrapper![$wrapper $e])|+)
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_flags [] $($rest)*]
+    };
+}
+
+/// Functions identically to [wrapped_list](crate::wrapped_list) with a wrapper
+/// that returns `bool`, but packs the results into a
+/// [`BitVec`](bitvec::vec::BitVec) for compact flag tables.
+///
+/// Requires the `bitvec` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_bits;
+///
+/// fn is_enabled(level: u8) -> bool {
+///     level >= 2
+/// }
+///
+/// let enabled = wrapped_bits![is_enabled; 1, 3, 2, 0];
+///
+/// assert_eq!(enabled.count_ones(), 2);
+/// assert!(enabled[1] && !enabled[3]);
+/// ```
+#[cfg(feature = "bitvec")]
+#[macro_export]
+macro_rules! wrapped_bits {
+    [$($rest:tt)*] => {
+        <$crate::__private::bitvec::vec::BitVec as ::core::iter::FromIterator<bool>>::from_iter(
+            $crate::wrapped_list![$($rest)*],
+        )
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use core::ops::BitOr;
+
+    #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+    struct Permissions(u32);
+
+    impl Permissions {
+        const READ: Self = Permissions(0b001);
+        const WRITE: Self = Permissions(0b010);
+        const EXECUTE: Self = Permissions(0b100);
+    }
+
+    impl BitOr for Permissions {
+        type Output = Self;
+
+        fn bitor(self, rhs: Self) -> Self {
+            Permissions(self.0 | rhs.0)
+        }
+    }
+
+    macro_rules! bit {
+        ($e:expr) => {
+            1u32 << $e
+        };
+    }
+
+    #[test]
+    fn flags() {
+        assert_eq!(
+            wrapped_flags![Permissions; 0b001, 0b100],
+            Permissions(0b101)
+        );
+        assert_eq!(wrapped_flags![Permissions => 0b010,], Permissions::WRITE);
+        assert_eq!(wrapped_flags![bit!; 0, 3, 4], 0b11001);
+        assert_eq!(wrapped_flags![<u8>::from; true, false], 1);
+    }
+
+    #[test]
+    fn flag_constants() {
+        assert_eq!(
+            wrapped_flags![Permissions::{READ, EXECUTE}],
+            Permissions(0b101)
+        );
+        assert_eq!(
+            wrapped_flags![self::Permissions::{READ, WRITE, EXECUTE,}],
+            Permissions(0b111)
+        );
+    }
+
+    #[cfg(feature = "bitvec")]
+    #[test]
+    fn bits() {
+        macro_rules! is_odd {
+            ($e:expr) => {
+                $e % 2 == 1
+            };
+        }
+
+        let bits = wrapped_bits![is_odd!; 1, 2, 3];
+        assert_eq!(
+            bits.iter().by_vals().collect::<std::vec::Vec<_>>(),
+            [true, false, true]
+        );
+        let bits = wrapped_bits![<bool>::from => true, false];
+        assert_eq!(bits.count_ones(), 1);
+        let bits = wrapped_bits![bool::from;];
+        assert!(bits.is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+/// Calls the wrapper with every element for its side effects only, returning
+/// `()` instead of an array of results.
+///
+/// Besides the wrappers accepted by [wrapped_list](crate::wrapped_list), a
+/// method on a local value, such as `registry.register`, can be used. Each call
+/// is a separate statement, so `#[must_use]` results like `Result` are still
+/// reported.
+///
+/// ```
+/// use wrapped_list::wrapped_for_each;
+///
+/// let mut registry = Vec::new();
+///
+/// wrapped_for_each![registry.push; "metrics", "health"];
+///
+/// assert_eq!(registry, ["metrics", "health"]);
+/// ```
+#[macro_export]
+macro_rules! wrapped_for_each {
+    [$receiver:ident $(. $method:ident)+ ; $($e:expr),* $(,)?] => {{
+        #[allow(unused_mut)]
+        let mut call = |element| $receiver $(. $method)+ (element);
+        $(call($e);)*
+    }};
+    [$receiver:ident $(. $method:ident)+ => $($rest:tt)*] => {
+        $crate::wrapped_for_each![$receiver $(. $method)+ ; $($rest)*]
+    };
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {{
+        $($crate::__apply_wrapper![$wrapper $e];)*
+    }};
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_for_each [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use core::cell::{Cell, RefCell};
+
+    struct Registry {
+        names: RefCell<[&'static str; 3]>,
+        len: Cell<usize>,
+    }
+
+    impl Registry {
+        fn register(&self, name: &'static str) {
+            let len = self.len.get();
+            self.names.borrow_mut()[len] = name;
+            self.len.set(len + 1);
+        }
+    }
+
+    struct App {
+        registry: Registry,
+    }
+
+    std::thread_local! {
+        static TOTAL: Cell<i32> = const { Cell::new(0) };
+    }
+
+    fn add(value: i32) {
+        TOTAL.with(|total| total.set(total.get() + value));
+    }
+
+    struct Adder;
+
+    impl Adder {
+        fn add(value: i32) {
+            add(value);
+        }
+    }
+
+    macro_rules! add_twice {
+        ($e:expr) => {{
+            add($e);
+            add($e);
+        }};
+    }
+
+    #[test]
+    fn method_wrappers() {
+        let app = App {
+            registry: Registry {
+                names: RefCell::new([""; 3]),
+                len: Cell::new(0),
+            },
+        };
+        wrapped_for_each![app.registry.register; "a", "b"];
+        let registry = &app.registry;
+        let result: () = wrapped_for_each![registry.register => "c",];
+        assert_eq!(result, ());
+        assert_eq!(*app.registry.names.borrow(), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn for_each() {
+        wrapped_for_each![add; 1, 2];
+        wrapped_for_each![add_twice! => 3];
+        wrapped_for_each![self::add; 4];
+        wrapped_for_each![<Adder>::add; 5];
+        wrapped_for_each![add;];
+        assert_eq!(TOTAL.with(Cell::get), 18);
+    }
+}
@@ -0,0 +1,69 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Wraps every element of an array with `wrapper`, the function counterpart of
+/// [wrapped_list](crate::wrapped_list).
+///
+/// Library code can use this to offer the same behavior without exposing the
+/// macros in its public API.
+///
+/// ```
+/// use wrapped_list::wrap_array;
+///
+/// let boxed = wrap_array([1, 2, 3], Box::new);
+///
+/// assert_eq!(boxed, [Box::new(1), Box::new(2), Box::new(3)]);
+/// ```
+pub fn wrap_array<T, W, F, const N: usize>(array: [T; N], wrapper: F) -> [W; N]
+where
+    F: FnMut(T) -> W,
+{
+    array.map(wrapper)
+}
+
+/// Wraps a clone of every element of a slice with `wrapper`, the function
+/// counterpart of [wrapped_vec](crate::wrapped_vec).
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use wrapped_list::wrap_slice;
+///
+/// let names = ["a", "b"];
+/// let owned = wrap_slice(&names, String::from);
+///
+/// assert_eq!(owned, ["a", "b"]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn wrap_slice<T, W, F>(slice: &[T], wrapper: F) -> Vec<W>
+where
+    T: Clone,
+    F: FnMut(T) -> W,
+{
+    slice.iter().cloned().map(wrapper).collect()
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array() {
+        assert_eq!(wrap_array([1, 2], Some), wrapped_list![Some; 1, 2]);
+        assert_eq!(wrap_array([0u8; 0], Some), [None; 0]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn slice() {
+        use std::boxed::Box;
+
+        let values = [1, 2, 3];
+        assert_eq!(
+            wrap_slice(&values, Box::new),
+            wrapped_vec![Box::new; 1, 2, 3]
+        );
+        assert_eq!(wrap_slice(&values[..1], |x| x * 10), [10]);
+    }
+}
@@ -0,0 +1,355 @@
+use core::array;
+
+/// Functions identically to [wrapped_list](crate::wrapped_list), but groups
+/// the wrapped elements into chunks of the given size, returning an array of
+/// arrays.
+///
+/// The number of elements must be a multiple of the chunk size, otherwise the
+/// macro fails to compile.
+///
+/// ```
+/// use wrapped_list::wrapped_chunks;
+///
+/// let pairs = wrapped_chunks![Some; 2; 1, 2, 3, 4];
+///
+/// assert_eq!(pairs, [[Some(1), Some(2)], [Some(3), Some(4)]]);
+///
+/// // `=>` can be used in place of the first `;`.
+/// let pairs = wrapped_chunks![Some => 2; 1, 2, 3, 4];
+///
+/// assert_eq!(pairs, [[Some(1), Some(2)], [Some(3), Some(4)]]);
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_chunks;
+///
+/// let pairs = wrapped_chunks![Some; 2; 1, 2, 3];
+/// ```
+#[macro_export]
+macro_rules! wrapped_chunks {
+    [@wrap [] $wrapper:tt $n:literal ; $($e:expr),* $(,)?] => {
+        $crate::__group![chunks $n; [$(::core::stringify!($e)),*]; [$($crate::__apply_wrapper![$wrapper $e]),*]]
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_chunks [] $($rest)*]
+    };
+}
+
+/// Functions identically to [wrapped_list](crate::wrapped_list), but returns
+/// every overlapping window of the given size, as an array of arrays.
+///
+/// Each element is wrapped once and then cloned into the windows that contain
+/// it, so the wrapped type must implement `Clone`. The window size must be
+/// between 1 and the number of elements, otherwise the macro fails to compile.
+///
+/// ```
+/// use wrapped_list::wrapped_windows;
+///
+/// let windows = wrapped_windows![Some; 2; 1, 2, 3];
+///
+/// assert_eq!(windows, [[Some(1), Some(2)], [Some(2), Some(3)]]);
+///
+/// let windows = wrapped_windows![Some => 2; 1, 2, 3];
+///
+/// assert_eq!(windows, [[Some(1), Some(2)], [Some(2), Some(3)]]);
+/// ```
+#[macro_export]
+macro_rules! wrapped_windows {
+    [@wrap [] $wrapper:tt $n:literal ; $($e:expr),* $(,)?] => {
+        $crate::__group![windows $n; [$(::core::stringify!($e)),*]; [$($crate::__apply_wrapper![$wrapper $e]),*]]
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_windows [] $($rest)*]
+    };
+}
+
+/// Wraps the elements of two lists of the same length and interleaves them,
+/// returning `[W(a1), W(b1), W(a2), W(b2), ...]`.
+///
+/// The lists are separated with `;`, and lists of different lengths fail to
+/// compile. The wrapper is called in the order of the result.
+///
+/// ```
+/// use wrapped_list::wrapped_interleave;
+///
+/// let stereo = wrapped_interleave![f32::from; 1u8, 3u8; 2u8, 4u8];
+///
+/// assert_eq!(stereo, [1.0, 2.0, 3.0, 4.0]);
+///
+/// // `=>` can be used in place of the first `;`.
+/// let stereo = wrapped_interleave![f32::from => 1u8, 3u8; 2u8, 4u8];
+///
+/// assert_eq!(stereo, [1.0, 2.0, 3.0, 4.0]);
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_interleave;
+///
+/// let stereo = wrapped_interleave![Some; 1, 3; 2];
+/// ```
+#[macro_export]
+macro_rules! wrapped_interleave {
+    [@wrap [] $wrapper:tt $($a:expr),* $(,)? ; $($b:expr),* $(,)?] => {
+        [$($crate::__apply_wrapper![$wrapper $a], $crate::__apply_wrapper![$wrapper $b]),*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_interleave [] $($rest)*]
+    };
+}
+
+/// Wraps the elements of several bracketed groups into a single flat array,
+/// whose length is the total number of elements.
+///
+/// Accepts the same wrappers and modifiers as
+/// [wrapped_list](crate::wrapped_list), so groups of literal data can be kept
+/// apart in the source while feeding an API that takes a flat array. Wrap the
+/// result in `Vec::from` for a `Vec`.
+///
+/// ```
+/// use wrapped_list::wrapped_flatten;
+///
+/// let pixels = wrapped_flatten![Some; [0, 1, 2], [], [3, 4]];
+///
+/// assert_eq!(pixels, [0, 1, 2, 3, 4].map(Some));
+/// ```
+#[macro_export]
+macro_rules! wrapped_flatten {
+    [@wrapper [$($wrapper:tt)*] ; $([$($e:expr),* $(,)?]),* $(,)?] => {
+        $crate::wrapped_list![$($wrapper)* ; $($($e,)*)*]
+    };
+    [@wrapper [$($wrapper:tt)*] => $([$($e:expr),* $(,)?]),* $(,)?] => {
+        $crate::wrapped_list![$($wrapper)* ; $($($e,)*)*]
+    };
+    [@wrapper [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by bracketed groups of elements")
+    };
+    [@wrapper [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::wrapped_flatten![@wrapper [$($wrapper)* $next] $($rest)*]
+    };
+    [$($rest:tt)*] => {
+        $crate::wrapped_flatten![@wrapper [] $($rest)*]
+    };
+}
+
+/// Transposes rows of elements separated with `;` and wraps them, returning an
+/// array of the columns.
+///
+/// The transposition happens during expansion, so the wrapper is called in the
+/// order of the result. Accepts the same wrappers as
+/// [wrapped_list](crate::wrapped_list), and rows of different lengths fail to
+/// compile.
+///
+/// ```
+/// use wrapped_list::wrapped_transpose;
+///
+/// let columns = wrapped_transpose![Some; 1, 2, 3; 4, 5, 6];
+///
+/// assert_eq!(columns, [[Some(1), Some(4)], [Some(2), Some(5)], [Some(3), Some(6)]]);
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_transpose;
+///
+/// let columns = wrapped_transpose![Some; 1, 2; 3];
+/// ```
+#[macro_export]
+macro_rules! wrapped_transpose {
+    [@wrapper [$($wrapper:tt)*] ; $($($e:expr),+ $(,)?);+] => {
+        $crate::wrapped_transpose![@transpose [$($wrapper)*] [] $([$($e),+])+]
+    };
+    [@wrapper [$($wrapper:tt)*] => $($($e:expr),+ $(,)?);+] => {
+        $crate::wrapped_transpose![@transpose [$($wrapper)*] [] $([$($e),+])+]
+    };
+    [@wrapper [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by the rows")
+    };
+    [@wrapper [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::wrapped_transpose![@wrapper [$($wrapper)* $next] $($rest)*]
+    };
+    [@transpose $wrapper:tt [$($columns:tt)*] $([])+] => {
+        [$($crate::wrapped_transpose![@column $wrapper $columns]),*]
+    };
+    [@transpose $wrapper:tt [$($columns:tt)*] $([$head:expr $(, $tail:expr)*])+] => {
+        $crate::wrapped_transpose![@transpose $wrapper [$($columns)* [$($head),+]] $([$($tail),*])+]
+    };
+    [@transpose $($rest:tt)*] => {
+        ::core::compile_error!("every row must have the same number of elements")
+    };
+    [@column [$($wrapper:tt)*] [$($e:expr),*]] => {
+        $crate::wrapped_list![$($wrapper)* ; $($e),*]
+    };
+    [$($rest:tt)*] => {
+        $crate::wrapped_transpose![@wrapper [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __group {
+    [$kind:ident $n:literal ; $names:expr ; $flat:expr] => {{
+        const __WRAPPED_LEN: usize = <[&str]>::len(&$names);
+        const __WRAPPED_GROUPS: usize = $crate::__private::group_count::$kind(__WRAPPED_LEN, $n);
+        $crate::__private::$kind::<_, $n, __WRAPPED_GROUPS, __WRAPPED_LEN>($flat)
+    }};
+}
+
+#[doc(hidden)]
+pub mod group_count {
+    pub const fn chunks(len: usize, size: usize) -> usize {
+        assert!(
+            matches!(len.checked_rem(size), Some(0)),
+            "the number of elements must be a multiple of the chunk size"
+        );
+        len / size
+    }
+
+    pub const fn windows(len: usize, size: usize) -> usize {
+        assert!(
+            size > 0 && size <= len,
+            "the window size must be between 1 and the number of elements"
+        );
+        len + 1 - size
+    }
+}
+
+#[doc(hidden)]
+pub fn chunks<T, const N: usize, const M: usize, const L: usize>(flat: [T; L]) -> [[T; N]; M] {
+    let mut iter = flat.into_iter();
+    array::from_fn(|_| array::from_fn(|_| iter.next().unwrap()))
+}
+
+#[doc(hidden)]
+pub fn windows<T: Clone, const N: usize, const M: usize, const L: usize>(
+    flat: [T; L],
+) -> [[T; N]; M] {
+    array::from_fn(|i| array::from_fn(|j| flat[i + j].clone()))
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use core::num::Wrapping;
+
+    #[derive(PartialEq, Eq, Debug, Clone)]
+    struct Wrapper(i32);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn chunks() {
+        assert_eq!(
+            wrapped_chunks![Wrapper; 2; 1, 2, 3, 4],
+            [[Wrapper(1), Wrapper(2)], [Wrapper(3), Wrapper(4)]]
+        );
+        assert_eq!(
+            wrapped_chunks![wrapper_macro!; 3; 1, 2, 3,],
+            [[Wrapper(1), Wrapper(2), Wrapper(3)]]
+        );
+        assert_eq!(
+            wrapped_chunks![derive Option<_>; 1; 1, 2],
+            [[Some(1)], [Some(2)]]
+        );
+        assert_eq!(
+            wrapped_chunks![<Option<i32>>::Some; 2; 1, 2],
+            [[Some(1), Some(2)]]
+        );
+        assert_eq!(
+            wrapped_chunks![Some => 2; 1, 2, 3, 4],
+            [[Some(1), Some(2)], [Some(3), Some(4)]]
+        );
+        assert_eq!(wrapped_chunks![wrapper_macro! => 1; 1], [[Wrapper(1)]]);
+        let empty: [[Wrapper; 2]; 0] = wrapped_chunks![Wrapper; 2;];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn windows() {
+        assert_eq!(
+            wrapped_windows![Wrapper; 2; 1, 2, 3],
+            [[Wrapper(1), Wrapper(2)], [Wrapper(2), Wrapper(3)]]
+        );
+        assert_eq!(
+            wrapped_windows![wrapper_macro!; 3; 1, 2, 3],
+            [[Wrapper(1), Wrapper(2), Wrapper(3)]]
+        );
+        assert_eq!(wrapped_windows![derive Option<_>; 1; 1], [[Some(1)]]);
+        assert_eq!(
+            wrapped_windows![Some => 2; 1, 2, 3],
+            [[Some(1), Some(2)], [Some(2), Some(3)]]
+        );
+        assert_eq!(wrapped_windows![wrapper_macro! => 1; 1], [[Wrapper(1)]]);
+    }
+
+    #[test]
+    fn transpose() {
+        assert_eq!(
+            wrapped_transpose![Wrapper; 1, 2, 3; 4, 5, 6,],
+            [
+                [Wrapper(1), Wrapper(4)],
+                [Wrapper(2), Wrapper(5)],
+                [Wrapper(3), Wrapper(6)]
+            ]
+        );
+        assert_eq!(
+            wrapped_transpose![wrapper_macro! => 1; 2],
+            [[Wrapper(1), Wrapper(2)]]
+        );
+        assert_eq!(
+            wrapped_transpose![derive Wrapping<_>; 1, 2],
+            [[Wrapping(1)], [Wrapping(2)]]
+        );
+        assert_eq!(
+            wrapped_transpose![<i64>::from; 1i32, 2i32; 3i32, 4i32],
+            [[1, 3], [2, 4]]
+        );
+    }
+
+    #[test]
+    fn flatten() {
+        assert_eq!(
+            wrapped_flatten![Wrapper; [1, 2], [3,],],
+            [Wrapper(1), Wrapper(2), Wrapper(3)]
+        );
+        assert_eq!(
+            wrapped_flatten![wrapper_macro! => [1], [], [2]],
+            [Wrapper(1), Wrapper(2)]
+        );
+        assert_eq!(
+            wrapped_flatten![#rev derive Wrapping<_>; [1], [2, 3]],
+            [Wrapping(3), Wrapping(2), Wrapping(1)]
+        );
+        let empty: [Wrapper; 0] = wrapped_flatten![Wrapper; []];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn interleave() {
+        assert_eq!(
+            wrapped_interleave![Wrapper; 1, 3; 2, 4,],
+            [Wrapper(1), Wrapper(2), Wrapper(3), Wrapper(4)]
+        );
+        assert_eq!(
+            wrapped_interleave![wrapper_macro!; 1; 2],
+            [Wrapper(1), Wrapper(2)]
+        );
+        assert_eq!(
+            wrapped_interleave![derive Wrapping<_>; 1; 2],
+            [Wrapping(1), Wrapping(2)]
+        );
+        assert_eq!(wrapped_interleave![<i64>::from; 1i32; 2i32], [1, 2]);
+        assert_eq!(
+            wrapped_interleave![Wrapper => 1; 2],
+            [Wrapper(1), Wrapper(2)]
+        );
+        assert_eq!(
+            wrapped_interleave![wrapper_macro! => 1; 2],
+            [Wrapper(1), Wrapper(2)]
+        );
+        let empty: [Wrapper; 0] = wrapped_interleave![Wrapper;;];
+        assert!(empty.is_empty());
+    }
+}
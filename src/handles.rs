@@ -0,0 +1,108 @@
+/// Wraps every element and inserts the results into a new
+/// [`Slab`](slab::Slab), returning the slab and the keys of the inserted
+/// values in order.
+///
+/// Requires the `slab` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_slab;
+///
+/// let (slab, [first, second]) = wrapped_slab![Box::new; 1, 2];
+///
+/// assert_eq!(slab[first], Box::new(1));
+/// assert_eq!(slab[second], Box::new(2));
+/// ```
+#[cfg(feature = "slab")]
+#[macro_export]
+macro_rules! wrapped_slab {
+    [$($rest:tt)*] => {{
+        let values = $crate::wrapped_list![$($rest)*];
+        let mut slab = $crate::__private::slab::Slab::with_capacity(values.len());
+        let keys = values.map(|value| slab.insert(value));
+        (slab, keys)
+    }};
+}
+
+/// Wraps every element and inserts the results into a new
+/// [`SlotMap`](slotmap::SlotMap) with the default key type, returning the map
+/// and the keys of the inserted values in order.
+///
+/// Requires the `slotmap` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_slotmap;
+///
+/// let (mut map, [player, enemy]) = wrapped_slotmap![String::from; "player", "enemy"];
+///
+/// map.remove(enemy);
+/// assert_eq!(map[player], "player");
+/// assert!(!map.contains_key(enemy));
+/// ```
+#[cfg(feature = "slotmap")]
+#[macro_export]
+macro_rules! wrapped_slotmap {
+    [$($rest:tt)*] => {{
+        let values = $crate::wrapped_list![$($rest)*];
+        let mut map = $crate::__private::slotmap::SlotMap::with_capacity(values.len());
+        let keys = values.map(|value| map.insert(value));
+        (map, keys)
+    }};
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "slab"))]
+mod slab_tests {
+    use slab::Slab;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn wrapped_slab() {
+        let (mut slab, keys) = wrapped_slab![Wrapper; 1, 2, 3];
+        assert_eq!(
+            keys.map(|key| &slab[key]),
+            [&Wrapper(1), &Wrapper(2), &Wrapper(3)]
+        );
+        assert_eq!(slab.remove(keys[1]), Wrapper(2));
+        assert_eq!(slab.len(), 2);
+
+        let (slab, [key]) = wrapped_slab![wrapper_macro! => 4];
+        assert_eq!(slab[key], Wrapper(4));
+
+        let (slab, keys): (Slab<Wrapper>, _) = wrapped_slab![Wrapper;];
+        assert!(slab.is_empty() && keys.is_empty());
+    }
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "slotmap"))]
+mod slotmap_tests {
+    use slotmap::{DefaultKey, SlotMap};
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    #[test]
+    fn wrapped_slotmap() {
+        let (mut map, keys) = wrapped_slotmap![Wrapper; 1, 2, 3];
+        assert_eq!(
+            keys.map(|key| &map[key]),
+            [&Wrapper(1), &Wrapper(2), &Wrapper(3)]
+        );
+        assert_eq!(map.remove(keys[0]), Some(Wrapper(1)));
+        assert_eq!(map.get(keys[0]), None);
+
+        let (map, [key]) = wrapped_slotmap![<Option<i32>>::Some => 4];
+        assert_eq!(map[key], Some(4));
+
+        let (map, keys): (SlotMap<DefaultKey, Wrapper>, _) = wrapped_slotmap![Wrapper;];
+        assert!(map.is_empty() && keys.is_empty());
+    }
+}
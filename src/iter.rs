@@ -0,0 +1,263 @@
+use core::fmt;
+use core::iter::{FusedIterator, Map};
+use core::ops::Deref;
+
+/// Extension trait that wraps every item of an iterator, the runtime
+/// counterpart of [wrapped_list](crate::wrapped_list).
+///
+/// ```
+/// use wrapped_list::WrapAll;
+///
+/// let boxed: Vec<Box<i32>> = (1..=3).wrap_all(Box::new).collect();
+///
+/// assert_eq!(boxed, [Box::new(1), Box::new(2), Box::new(3)]);
+/// ```
+pub trait WrapAll: Iterator + Sized {
+    /// Returns an iterator that lazily wraps every item with `wrapper`.
+    fn wrap_all<W, F>(self, wrapper: F) -> WrapIter<Self, F>
+    where
+        F: FnMut(Self::Item) -> W,
+    {
+        WrapIter {
+            iter: self,
+            wrapper,
+        }
+    }
+}
+
+impl<I: Iterator> WrapAll for I {}
+
+/// Iterator returned by [`WrapAll::wrap_all`] and [wrapped_iter](crate::wrapped_iter).
+///
+/// It is double-ended, exact-size, and fused whenever the wrapped iterator is.
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct WrapIter<I, F> {
+    iter: I,
+    wrapper: F,
+}
+
+impl<I: fmt::Debug, F> fmt::Debug for WrapIter<I, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WrapIter")
+            .field("iter", &self.iter)
+            .finish()
+    }
+}
+
+impl<I, W, F> Iterator for WrapIter<I, F>
+where
+    I: Iterator,
+    F: FnMut(I::Item) -> W,
+{
+    type Item = W;
+
+    fn next(&mut self) -> Option<W> {
+        self.iter.next().map(&mut self.wrapper)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<W> {
+        self.iter.nth(n).map(&mut self.wrapper)
+    }
+
+    fn fold<B, G>(self, init: B, mut g: G) -> B
+    where
+        G: FnMut(B, W) -> B,
+    {
+        let mut wrapper = self.wrapper;
+        self.iter.fold(init, move |acc, item| g(acc, wrapper(item)))
+    }
+}
+
+impl<I, W, F> DoubleEndedIterator for WrapIter<I, F>
+where
+    I: DoubleEndedIterator,
+    F: FnMut(I::Item) -> W,
+{
+    fn next_back(&mut self) -> Option<W> {
+        self.iter.next_back().map(&mut self.wrapper)
+    }
+}
+
+impl<I, W, F> ExactSizeIterator for WrapIter<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(I::Item) -> W,
+{
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<I, W, F> FusedIterator for WrapIter<I, F>
+where
+    I: FusedIterator,
+    F: FnMut(I::Item) -> W,
+{
+}
+
+/// Extension trait that maps every wrapper of an iterator back to the value
+/// inside it, the inverse of [`WrapAll`].
+///
+/// ```
+/// use std::rc::Rc;
+/// use wrapped_list::{UnwrapWrapped, WrapAll};
+///
+/// let shared: Vec<Rc<String>> = ["a", "b"].into_iter().map(String::from).wrap_all(Rc::new).collect();
+/// let owned: Vec<String> = shared.into_iter().unwrap_cloned().collect();
+///
+/// assert_eq!(owned, ["a", "b"]);
+/// ```
+pub trait UnwrapWrapped: Iterator + Sized {
+    /// Returns an iterator that converts every wrapper with [`Into`].
+    fn unwrap_wrapped<T>(self) -> Map<Self, fn(Self::Item) -> T>
+    where
+        Self::Item: Into<T>,
+    {
+        self.map(Into::into)
+    }
+
+    /// Returns an iterator that clones the value every wrapper dereferences
+    /// to, for shared wrappers such as `Rc` and `Arc`.
+    fn unwrap_cloned<T>(self) -> Map<Self, fn(Self::Item) -> T>
+    where
+        Self::Item: Deref<Target = T>,
+        T: Clone,
+    {
+        self.map(|wrapper| wrapper.deref().clone())
+    }
+
+    /// Returns an iterator that takes the value out of every wrapper with
+    /// `extract`.
+    fn unwrap_wrapped_with<T, F>(self, extract: F) -> Map<Self, F>
+    where
+        F: FnMut(Self::Item) -> T,
+    {
+        self.map(extract)
+    }
+}
+
+impl<I: Iterator> UnwrapWrapped for I {}
+
+/// Functions identically to [wrapped_list](crate::wrapped_list), but returns a
+/// lazy [`WrapIter`] that only calls the wrapper as items are consumed.
+///
+/// ```
+/// use wrapped_list::wrapped_iter;
+///
+/// let mut iter = wrapped_iter![Box::new; 1, 2, 3];
+///
+/// assert_eq!(iter.len(), 3);
+/// assert_eq!(iter.next_back(), Some(Box::new(3)));
+/// assert_eq!(iter.collect::<Vec<_>>(), [Box::new(1), Box::new(2)]);
+/// ```
+#[macro_export]
+macro_rules! wrapped_iter {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::WrapAll::wrap_all(
+            ::core::iter::IntoIterator::into_iter([$($e),*]),
+            $crate::__apply_wrapper![@fn $wrapper],
+        )
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_iter [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::{UnwrapWrapped, WrapAll};
+    use std::boxed::Box;
+    use std::vec::Vec;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    #[test]
+    fn wrap_all() {
+        let wrapped: Vec<Wrapper> = [1, 2, 3].into_iter().wrap_all(Wrapper).collect();
+        assert_eq!(wrapped, [Wrapper(1), Wrapper(2), Wrapper(3)]);
+        let boxed: Vec<Box<i32>> = (1..3).wrap_all(Box::new).collect();
+        assert_eq!(boxed, [Box::new(1), Box::new(2)]);
+    }
+
+    #[test]
+    fn unwrap_wrapped() {
+        use core::num::Wrapping;
+        use std::rc::Rc;
+
+        let values: Vec<i32> = (1..=2)
+            .wrap_all(Box::new)
+            .unwrap_wrapped_with(|b| *b)
+            .collect();
+        assert_eq!(values, [1, 2]);
+        let shared = [Rc::new(3), Rc::new(4)];
+        let cloned: Vec<i32> = shared.iter().cloned().unwrap_cloned().rev().collect();
+        assert_eq!(cloned, [4, 3]);
+        let converted: Vec<Box<str>> = ["a"].into_iter().unwrap_wrapped().collect();
+        assert_eq!(converted, [Box::from("a")]);
+        let inner: Vec<u8> = [Wrapping(5u8)]
+            .into_iter()
+            .unwrap_wrapped_with(|w| w.0)
+            .collect();
+        assert_eq!(inner, [5]);
+    }
+
+    #[test]
+    fn wrap_all_is_lazy() {
+        let mut calls = 0;
+        let mut iter = (1..=3).wrap_all(|x| {
+            calls += 1;
+            Wrapper(x)
+        });
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        assert_eq!(iter.next(), Some(Wrapper(1)));
+        drop(iter);
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn adapter_traits() {
+        fn assert_fused<I: core::iter::FusedIterator>(_: &I) {}
+
+        let mut iter = (1..5).wrap_all(Wrapper);
+        assert_fused(&iter);
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next_back(), Some(Wrapper(4)));
+        assert_eq!(iter.nth(1), Some(Wrapper(2)));
+        assert_eq!(iter.len(), 1);
+        assert_eq!(iter.rev().collect::<Vec<_>>(), [Wrapper(3)]);
+    }
+
+    #[test]
+    fn wrapped_iter() {
+        macro_rules! wrapper_macro {
+            ($e:expr) => {
+                Wrapper($e)
+            };
+        }
+
+        let iter = wrapped_iter![Wrapper; 1, 2];
+        assert_eq!(iter.len(), 2);
+        assert_eq!(iter.collect::<Vec<_>>(), [Wrapper(1), Wrapper(2)]);
+        assert_eq!(
+            wrapped_iter![wrapper_macro!; 1, 2]
+                .rev()
+                .collect::<Vec<_>>(),
+            [Wrapper(2), Wrapper(1)]
+        );
+        assert_eq!(
+            wrapped_iter![derive Option<i32> => 1].collect::<Vec<_>>(),
+            [Some(1)]
+        );
+        assert_eq!(
+            wrapped_iter![<Option<i32>>::Some => 1].collect::<Vec<_>>(),
+            [Some(1)]
+        );
+    }
+}
@@ -0,0 +1,63 @@
+/// Builds a `Vec<serde_json::Value>` by passing every element to
+/// `serde_json::json!`.
+///
+/// This is `wrapped_vec![json!; ...]` without the prefix, and the elements use
+/// the full `json!` syntax, including object and array literals.
+///
+/// Requires the `serde_json` feature.
+///
+/// ```
+/// use serde_json::json;
+/// use wrapped_list::json_list;
+///
+/// let values = json_list![1, "two", {"k": 3}, null, -4];
+///
+/// assert_eq!(values, [json!(1), json!("two"), json!({"k": 3}), json!(null), json!(-4)]);
+/// ```
+#[cfg(feature = "serde_json")]
+#[macro_export]
+macro_rules! json_list {
+    [$($rest:tt)*] => {
+        match $crate::__private::serde_json::json!([$($rest)*]) {
+            $crate::__private::serde_json::Value::Array(values) => values,
+            _ => ::core::unreachable!(),
+        }
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+    use serde_json::{json, Value};
+    use std::vec::Vec;
+
+    #[test]
+    fn json_list() {
+        assert_eq!(
+            json_list![1, "two", {"k": [3]}, [true, null]],
+            [
+                json!(1),
+                json!("two"),
+                json!({"k": [3]}),
+                json!([true, null])
+            ]
+        );
+        let x = 2;
+        assert_eq!(
+            json_list![-1, x + 1, {"x": x},],
+            [json!(-1), json!(3), json!({"x": 2})]
+        );
+        assert_eq!(
+            json_list![
+                -1, -2, -3, -4, -5, -6, -7, -8, -9, -10, -11, -12, -13, -14, -15, -16, -17, -18,
+                -19, -20, -21, -22, -23, -24, -25, -26, -27, -28, -29, -30, -31, -32, -33, -34,
+                -35, -36, -37, -38, -39, -40, -41, -42, -43, -44, -45, -46, -47, -48, -49, -50,
+                -51, -52, -53, -54, -55, -56, -57, -58, -59, -60, -61, -62, -63, -64, -65, -66,
+                -67, -68, -69, -70, -71, -72, -73, -74, -75, -76, -77, -78, -79, -80,
+            ],
+            (1..=80).map(|n| json!(-n)).collect::<Vec<_>>()
+        );
+        let empty: Vec<Value> = json_list![];
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,170 @@
+use core::cell::{Cell, OnceCell};
+use core::fmt;
+use core::ops::Deref;
+
+/// A value that is only passed to its wrapper the first time it is accessed.
+///
+/// Created by [lazy_wrapped_list](crate::lazy_wrapped_list). Dereferencing a
+/// slot wraps its value if that hasn't happened yet, so an expensive wrapper is
+/// only called for the slots that are actually used.
+///
+/// ```
+/// use wrapped_list::LazyWrapped;
+///
+/// let slot = LazyWrapped::new(2, |x: i32| x.pow(10));
+///
+/// assert!(!slot.is_wrapped());
+/// assert_eq!(*slot, 1024);
+/// assert!(slot.is_wrapped());
+/// ```
+pub struct LazyWrapped<T, W> {
+    value: Cell<Option<T>>,
+    wrapped: OnceCell<W>,
+    wrapper: fn(T) -> W,
+}
+
+impl<T, W> LazyWrapped<T, W> {
+    /// Creates a slot that wraps `value` with `wrapper` on first access.
+    pub const fn new(value: T, wrapper: fn(T) -> W) -> Self {
+        LazyWrapped {
+            value: Cell::new(Some(value)),
+            wrapped: OnceCell::new(),
+            wrapper,
+        }
+    }
+
+    /// Returns the wrapped value, calling the wrapper if needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an earlier call to the wrapper panicked, since the value was
+    /// moved into that call.
+    pub fn get(&self) -> &W {
+        self.wrapped.get_or_init(|| {
+            let value = self
+                .value
+                .take()
+                .expect("the wrapper panicked during an earlier access");
+            (self.wrapper)(value)
+        })
+    }
+
+    /// Returns whether the wrapper has been called.
+    pub fn is_wrapped(&self) -> bool {
+        self.wrapped.get().is_some()
+    }
+
+    /// Consumes the slot and returns the wrapped value, calling the wrapper if
+    /// needed.
+    pub fn into_inner(self) -> W {
+        self.get();
+        match self.wrapped.into_inner() {
+            Some(wrapped) => wrapped,
+            None => unreachable!(),
+        }
+    }
+}
+
+impl<T, W> Deref for LazyWrapped<T, W> {
+    type Target = W;
+
+    fn deref(&self) -> &W {
+        self.get()
+    }
+}
+
+impl<T, W: fmt::Debug> fmt::Debug for LazyWrapped<T, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.wrapped.get() {
+            Some(wrapped) => f.debug_tuple("LazyWrapped").field(wrapped).finish(),
+            None => f.write_str("LazyWrapped(<unwrapped>)"),
+        }
+    }
+}
+
+/// Functions identically to [wrapped_list](crate::wrapped_list), but returns an
+/// array of [`LazyWrapped`] slots that only call the wrapper when they are
+/// first accessed.
+///
+/// The elements themselves are still evaluated eagerly. The wrapper must
+/// coerce to a function pointer, so macro wrappers can't refer to local
+/// variables.
+///
+/// ```
+/// use wrapped_list::lazy_wrapped_list;
+///
+/// fn expensive(seed: u64) -> u64 {
+///     (0..seed).sum()
+/// }
+///
+/// let slots = lazy_wrapped_list![expensive; 10, 1_000_000, 20];
+///
+/// assert_eq!(*slots[2], 190);
+/// assert!(!slots[1].is_wrapped());
+/// ```
+#[macro_export]
+macro_rules! lazy_wrapped_list {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        [$($crate::LazyWrapped::new($e, $crate::__apply_wrapper![@fn $wrapper])),*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list lazy_wrapped_list [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::LazyWrapped;
+    use core::cell::Cell;
+    use core::num::Wrapping;
+    use std::format;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    std::thread_local! {
+        static CALLS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    fn counted(value: i32) -> Wrapper {
+        CALLS.with(|calls| calls.set(calls.get() + 1));
+        Wrapper(value)
+    }
+
+    #[test]
+    fn wraps_on_first_access() {
+        let slots = lazy_wrapped_list![counted; 1, 2, 3];
+        assert_eq!(CALLS.with(Cell::get), 0);
+        assert_eq!(*slots[1], Wrapper(2));
+        assert_eq!(slots[1].0, 2);
+        assert_eq!(CALLS.with(Cell::get), 1);
+        assert!(!slots[0].is_wrapped());
+        assert_eq!(format!("{:?}", slots[0]), "LazyWrapped(<unwrapped>)");
+        assert_eq!(format!("{:?}", slots[1]), "LazyWrapped(Wrapper(2))");
+    }
+
+    #[test]
+    fn into_inner() {
+        let slot = LazyWrapped::new(1, Wrapper);
+        assert_eq!(slot.into_inner(), Wrapper(1));
+    }
+
+    #[test]
+    fn lazy_wrapped_list() {
+        let [a, b] = lazy_wrapped_list![wrapper_macro! => 1, 2];
+        assert_eq!((a.into_inner(), b.into_inner()), (Wrapper(1), Wrapper(2)));
+        let [a] = lazy_wrapped_list![derive Wrapping<_>; 1];
+        assert_eq!(*a, Wrapping(1));
+        let [a] = lazy_wrapped_list![<Option<i32>>::Some; 1];
+        assert_eq!(*a, Some(1));
+        let empty: [LazyWrapped<i32, Wrapper>; 0] = lazy_wrapped_list![Wrapper;];
+        assert!(empty.is_empty());
+    }
+}
@@ -54,6 +54,16 @@
 //! assert_eq!(doubled, [2, 4, 6]);
 //! ```
 //!
+//! Generic arguments, including lifetimes, and qualified paths are accepted too:
+//!
+//! ```
+//! use wrapped_list::wrapped_list;
+//!
+//! let parsed = wrapped_list![<i64 as From<i32>>::from; 1, 2, 3];
+//!
+//! assert_eq!(parsed, [1i64, 2, 3]);
+//! ```
+//!
 //! ### Wrap values with a macro
 //!
 //! ```
@@ -69,46 +79,1435 @@
 //!
 //! assert_eq!(one_more, [2, 3, 4]);
 //! ```
+//!
+//! Macros can also be referred to by their path:
+//!
+//! ```
+//! use wrapped_list::wrapped_list;
+//!
+//! mod ops {
+//!     macro_rules! double {
+//!         ($e:expr) => {
+//!             $e * 2
+//!         };
+//!     }
+//!
+//!     pub(crate) use double;
+//! }
+//!
+//! let doubled = wrapped_list![ops::double!; 1, 2, 3];
+//!
+//! assert_eq!(doubled, [2, 4, 6]);
+//! ```
+//!
+//! ### Separate the wrapper with `=>`
+//!
+//! The wrapper can also be separated from the elements with `=>` instead of `;`:
+//!
+//! ```
+//! use wrapped_list::wrapped_list;
+//!
+//! let boxed_items = wrapped_list![Box::new => 1, 2, 3];
+//!
+//! assert_eq!(boxed_items, [Box::new(1), Box::new(2), Box::new(3)]);
+//! ```
+//!
+//! ## Delimiters
+//!
+//! Every macro in this crate can be invoked with `()`, `[]`, or `{}`:
+//!
+//! ```
+//! use wrapped_list::{wrapped_list, wrapped_tuple};
+//!
+//! assert_eq!(wrapped_list!(Some; 1, 2), wrapped_list! { Some; 1, 2 });
+//! assert_eq!(wrapped_tuple![Some; 1, 2], (Some(1), Some(2)));
+//! ```
+//!
+//! ## Empty lists
+//!
+//! An empty list expands to an empty array, an empty `Vec`, or the unit tuple `()`.
+//! When nothing else constrains the element type, it can be given after the wrapper:
+//!
+//! ```
+//! use wrapped_list::{wrapped_list, wrapped_tuple, wrapped_vec};
+//!
+//! let empty = wrapped_list![Box::new: Box<i32>;];
+//! assert_eq!(empty, []);
+//!
+//! let empty = wrapped_vec![Box::new: Box<i32>;];
+//! assert!(empty.is_empty());
+//!
+//! let unit = wrapped_tuple!(Box::new;);
+//! assert_eq!(unit, ());
+//! ```
+//!
+//! ## Tuples
+//!
+//! `wrapped_tuple!` builds a tuple instead of an array, so the elements can have different
+//! types. A single element produces a 1-tuple:
+//!
+//! ```
+//! use wrapped_list::wrapped_tuple;
+//!
+//! assert_eq!(wrapped_tuple!(Some; 1, "two"), (Some(1), Some("two")));
+//! assert_eq!(wrapped_tuple!(Some; 1), (Some(1),));
+//! ```
+//!
+//! Before 2.0, `wrapped_tuple!(W; x)` expanded to the bare value `W(x)`. Code that relied on
+//! this should take the value out of the 1-tuple with `.0`.
+//!
+//! ## Collection newtype
+//!
+//! With the `alloc` feature, prefix the wrapper with `#newtype` to collect the values into a
+//! `WrappedList`, a `Vec` newtype that knows about the wrapping:
+//!
+//! ```
+//! use wrapped_list::{wrapped_vec, WrappedList};
+//!
+//! let mut list: WrappedList<Box<i32>> = wrapped_vec![#newtype Box::new; 1, 2];
+//! list.push_wrapped(3);
+//!
+//! assert_eq!(list.iter_inner().sum::<i32>(), 6);
+//! ```
+//!
+//! With the `std` feature, `wrapped_hashmap!` accepts the same modifier and returns a `WrappedMap`.
+//!
+//! Collections built at runtime get the same treatment through the `IntoWrapped` trait, which is
+//! implemented for `Vec`, arrays, and iterators:
+//!
+//! ```
+//! use wrapped_list::IntoWrapped;
+//!
+//! let list = vec![1, 2].into_wrapped(Box::new);
+//!
+//! assert_eq!(list.iter_inner().sum::<i32>(), 3);
+//! ```
+//!
+//! `WrappedVec` does the same for values pushed one at a time, wrapping each as it is added.
+//!
+//! ## Large lists
+//!
+//! Prefix the wrapper with `#compact` to expand to a single `map` over an array of the raw
+//! values instead of one wrapper call per element, which keeps compile times down for lists
+//! with thousands of elements:
+//!
+//! ```
+//! use wrapped_list::wrapped_list;
+//!
+//! let list = wrapped_list![#compact Some; 1, 2, 3];
+//!
+//! assert_eq!(list, [Some(1), Some(2), Some(3)]);
+//! ```
+//!
+//! Lists still expand to one array literal, and building them needs a temporary as large as
+//! the whole array. For generated inputs with tens of thousands of elements, use `wrapped!`
+//! from the `proc-macro` feature instead. It writes lists longer than 1024 elements into
+//! uninitialized storage a few elements at a time. `wrapped_list!`, `wrapped_vec!`, and their
+//! `#compact` and `#rev` forms never take this path, even with the `proc-macro` feature on.
+//!
+//! ## Prelude
+//!
+//! `wrapped_list::prelude::*` imports every macro, trait, and collection type enabled by the
+//! current features.
+//!
+//! ## Optional features
+//!
+//! - `std` (default): enables `alloc` and the `HashMap` based macros.
+//! - `alloc`: enables the macros that allocate, such as `wrapped_vec!`.
+//!   Without it the crate is `no_std` and allocation-free.
+//! - `proc-macro`: `wrapped!`, a procedural version of `wrapped_list!` with precise errors,
+//!   `#[wrapped_array]` for `static` and `const` tables, `#[wrap_args]` for functions, and `#[derive(Wrap, Variants, Fields)]`.
+//! - `anyhow`: `context_list!` attaches per-element context to fallible wrappers.
+//! - `axum`: `wrapped_routes!` builds a `Router` with every handler wrapped by the same method
+//!   router, such as `get`.
+//! - `bevy`: `wrapped_systems!` converts a list of system functions into boxed `bevy_ecs`
+//!   systems.
+//! - `bincode`: `Encode` and `Decode` for `WrappedList` and `WrappedMap`.
+//! - `bitvec`: `wrapped_bits!` packs the booleans returned by a wrapper into a `BitVec`.
+//! - `clap`: `wrapped_args!` and `wrapped_subcommands!` build clap arguments and subcommands
+//!   from a list of names, optionally chaining them onto a `Command`.
+//! - `criterion`: `wrapped_benches!` generates one benchmark per element.
+//! - `dashmap`: `wrapped_dashmap!` builds a concurrent `DashMap` with wrapped values.
+//! - `egui`: `wrapped_widgets!` boxes widgets of different types into a list of `BoxedWidget`s.
+//! - `futures`: `wrapped_unordered!` collects async wrapper calls into a `FuturesUnordered`.
+//! - `generic-array`: `wrapped_generic_array!` returns a `GenericArray` with a `typenum` length.
+//! - `iced`: `wrapped_elements!` converts widgets of different types into a list of `Element`s.
+//! - `linkme`: `#[wrapped_submit]` registers wrapped `static` items from any crate into a
+//!   `wrapped_distributed_slice!` registry, gathered into one list with `wrapped_collect!`.
+//! - `nom`: `wrapped_alt!` combines a list of parsers into a single `alt`.
+//! - `phf`: `wrapped_phf_map!` builds a compile-time `phf::Map` with wrapped values.
+//! - `priority-queue`: `wrapped_priority_queue!` builds a `PriorityQueue` from wrapped items and
+//!   their priorities.
+//! - `proptest`: `wrapped_strategy!` builds a strategy that generates vectors of wrapped values.
+//! - `pyo3`: `wrapped_pylist!` builds a Python `list` of wrapped values.
+//! - `quote`: `wrapped_tokens!` generates wrapped token streams for proc-macro authors.
+//! - `rayon`: `wrapped_par_vec!` applies the wrapper to the elements in parallel.
+//! - `regex`: `regex_list!` validates regex patterns at compile time.
+//! - `rkyv`: zero-copy archiving for `WrappedList` and `WrappedMap`, which archive as the
+//!   `ArchivedVec` and `ArchivedHashMap` of their wrapped values.
+//! - `ringbuffer`: `wrapped_ring_buffer!` pre-fills a bounded `ConstGenericRingBuffer`.
+//! - `serde`: `Serialize` and `Deserialize` for `WrappedList` and `WrappedMap`, and the
+//!   `serde_wrap` module for wrapping fields during deserialization.
+//! - `serde_json`: `json_list!` passes every element to `serde_json::json!`.
+//! - `slab`: `wrapped_slab!` inserts wrapped values into a `Slab` and returns their keys.
+//! - `slotmap`: `wrapped_slotmap!` inserts wrapped values into a `SlotMap` and returns their keys.
+//! - `sqlx`: `wrapped_binds!` chains a `.bind(...)` call per wrapped parameter onto a query.
+//! - `strum`: `wrapped_all_variants!` wraps every variant of an enum deriving `EnumIter` and
+//!   `EnumCount` into an array.
+//! - `time`: `date_list!` parses date literals at compile time into `time` values.
+//! - `tokio`: `spawned_list!` spawns a task per element and returns their join handles.
+//! - `tower`: `wrapped_services!` and `wrapped_layers!` box services and layers of different
+//!   types, or fold a list of layers onto a `ServiceBuilder`.
+//! - `tracing`: the `#traced` modifier runs every wrapper call inside a span recording the
+//!   element index and source expression.
+//! - `vec1`: `wrapped_vec1!` returns a non-empty `Vec1`, rejecting empty lists at compile time.
+//! - `wasm-bindgen`: `wrapped_js_array!` collects wrapped values into a `js_sys::Array`.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+extern crate self as wrapped_list;
+
+/// Macro to wrap a list of values with a function, object, or another macro.
+///
+/// See the [examples](crate#examples) to learn more.
+///
+/// Prefix the wrapper with `#compact` to wrap the elements with a single `map`
+/// call over an array of the raw values, which keeps the expansion small for
+/// lists with thousands of elements.
+///
+/// Prefix the wrapper with `#rev` to emit the elements in reverse order, which
+/// is handy for stacks that are easier to read top first. The wrapper is also
+/// called in reverse order:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// let stack = wrapped_list![#rev Some; 1, 2, 3];
+///
+/// assert_eq!(stack, [Some(3), Some(2), Some(1)]);
+/// ```
+///
+/// Prefix the wrapper with `#range` to wrap every value of a range of integer
+/// or `char` literals instead of a list of elements. The number of values is
+/// computed at compile time, and `.step_by` with a literal step is supported
+/// too:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// let tens = wrapped_list![#range Some; (0..100).step_by(10)];
+///
+/// assert_eq!(tens.len(), 10);
+/// assert_eq!(tens[9], Some(90));
+///
+/// let codes = wrapped_list![#range u32::from; 'a'..='z'];
+///
+/// assert_eq!(codes[25], 0x7a);
+/// ```
+///
+/// With the `proc-macro` feature, prefix the wrapper with `#unique` to reject
+/// duplicate literal elements at compile time, see [wrapped].
+///
+/// Prefix the wrapper with `#const` to evaluate the whole list at compile time.
+/// The wrapper and the elements must then be const-evaluable, such as a tuple
+/// struct wrapping literals, or the build fails. Borrowing the result gives a
+/// `&'static` reference to a table in read-only data, as long as the wrapped
+/// type has no interior mutability or `Drop` impl:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Celsius(f32);
+///
+/// let table: &'static [Celsius; 3] = &wrapped_list![#const Celsius; 0.0, 37.0, 100.0];
+///
+/// assert_eq!(table[1], Celsius(37.0));
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_list;
+///
+/// let boxed = wrapped_list![#const Box::new; 1, 2];
+/// ```
+///
+/// With the `tracing` feature, prefix the wrapper with `#traced` to run every
+/// wrapper call inside a span recording the element index and its source
+/// expression.
+///
+/// Follow a function or macro wrapper with `^` and an integer literal from 1 to
+/// 8 to apply it that many times, which is handy for deeply nested test values:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// let nested = wrapped_list![Box::new ^ 2; 1, 2];
+///
+/// assert_eq!(nested, [Box::new(Box::new(1)), Box::new(Box::new(2))]);
+/// ```
+///
+/// Prefix an `unsafe` function or macro wrapper with `unsafe` to call it in an
+/// `unsafe` block. Only the wrapper call is inside the block, the elements are
+/// evaluated before it:
+///
+/// ```
+/// use std::ptr::NonNull;
+/// use wrapped_list::wrapped_list;
+///
+/// let mut values = [1, 2];
+/// let [a, b] = &mut values;
+/// // SAFETY: references are never null.
+/// let pointers = wrapped_list![unsafe NonNull::new_unchecked; a as *mut i32, b as *mut i32];
+///
+/// assert_eq!(unsafe { *pointers[1].as_ptr() }, 2);
+/// ```
+///
+/// A struct literal with one field name and a base expression as the wrapper
+/// puts each element in that field and takes the other fields from the base,
+/// which is evaluated once per element:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// #[derive(Debug, Default, PartialEq)]
+/// struct Task {
+///     payload: u32,
+///     retries: u8,
+/// }
+///
+/// let tasks = wrapped_list![Task { payload, ..Default::default() }; 1, 2];
+///
+/// assert_eq!(tasks[1], Task { payload: 2, retries: 0 });
+/// ```
+///
+/// A call with a `_` placeholder as one of its arguments puts each element in
+/// place of the placeholder, which covers tuple structs with more than one
+/// field:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Entry(&'static str, u8);
+///
+/// let entries = wrapped_list![Entry(_, 0); "a", "b"];
+///
+/// assert_eq!(entries, [Entry("a", 0), Entry("b", 0)]);
+/// ```
+///
+/// Add `len = N;` after the wrapper to fail the build unless there are exactly
+/// `N` elements, which keeps protocol tables and parallel arrays from drifting
+/// apart:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// const CHANNELS: usize = 3;
+///
+/// let gains = wrapped_list![f32::from; len = CHANNELS; 1u8, 2u8, 4u8];
+///
+/// assert_eq!(gains, [1.0, 2.0, 4.0]);
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_list;
+///
+/// let gains = wrapped_list![f32::from; len = 3; 1u8, 2u8];
+/// ```
+///
+/// Separate groups of elements with `;` to get an array of arrays, one per
+/// group. Every group must have the same number of elements:
+///
+/// ```
+/// use wrapped_list::wrapped_list;
+///
+/// let grid = wrapped_list![Some; 1, 2; 3, 4];
+///
+/// assert_eq!(grid, [[Some(1), Some(2)], [Some(3), Some(4)]]);
+/// ```
+#[macro_export]
+macro_rules! wrapped_list {
+    [#compact $($rest:tt)*] => {
+        $crate::__compact![list; $($rest)*]
+    };
+    [#const $($rest:tt)*] => {
+        const { $crate::wrapped_list![$($rest)*] }
+    };
+    [#traced $($rest:tt)*] => {
+        $crate::__traced![$($rest)*]
+    };
+    [#rev $($rest:tt)*] => {
+        $crate::__rev![list [] $($rest)*]
+    };
+    [#range $($rest:tt)*] => {
+        $crate::__range![list [] $($rest)*]
+    };
+    [#unique $($rest:tt)*] => {
+        $crate::__unique![$($rest)*]
+    };
+    [unsafe $wrapper:path ; $($e:expr),* $(,)?] => {
+        [$(match $e {
+            element => unsafe { $wrapper(element) },
+        }),*]
+    };
+    [unsafe $wrapper:ident! ; $($e:expr),* $(,)?] => {
+        [$(match $e {
+            element => unsafe { $wrapper!(element) },
+        }),*]
+    };
+    [unsafe $wrapper:path => $($rest:tt)*] => {
+        $crate::wrapped_list![unsafe $wrapper ; $($rest)*]
+    };
+    [unsafe $wrapper:ident! => $($rest:tt)*] => {
+        $crate::wrapped_list![unsafe $wrapper! ; $($rest)*]
+    };
+    [$($wrapper:ident)::+ ^ $depth:tt ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_list![@nest [$($wrapper)::+] $depth; $($e),*]
+    };
+    [$($wrapper:ident)::+ ^ $depth:tt => $($rest:tt)*] => {
+        $crate::wrapped_list![$($wrapper)::+ ^ $depth ; $($rest)*]
+    };
+    [$wrapper:ident! ^ $depth:tt ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_list![@nest [$wrapper!] $depth; $($e),*]
+    };
+    [$wrapper:ident! ^ $depth:tt => $($rest:tt)*] => {
+        $crate::wrapped_list![$wrapper! ^ $depth ; $($rest)*]
+    };
+    [@nest $wrapper:tt $depth:tt; $($e:expr),*] => {
+        [$($crate::__nest!($wrapper $depth $e)),*]
+    };
+    [$($wrapper:ident)::+ ($($args:tt)*) ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_list![@placeholder [$($wrapper)::+] ($($args)*); $($e),*]
+    };
+    [$($wrapper:ident)::+ ($($args:tt)*) => $($rest:tt)*] => {
+        $crate::wrapped_list![$($wrapper)::+ ($($args)*) ; $($rest)*]
+    };
+    [@placeholder $wrapper:tt $args:tt; $($e:expr),*] => {
+        [$($crate::__placeholder![$wrapper $args $e]),*]
+    };
+    [$wrapper:path ; len = $len:expr ; $($rest:tt)*] => {{
+        let list: [_; $len] = $crate::wrapped_list![$wrapper ; $($rest)*];
+        list
+    }};
+    [$wrapper:ident! ; len = $len:expr ; $($rest:tt)*] => {{
+        let list: [_; $len] = $crate::wrapped_list![$wrapper! ; $($rest)*];
+        list
+    }};
+    [$wrapper:path ; $($e:expr),* $(,)?] => {
+        [$($wrapper($e)),*]
+    };
+    [$wrapper:ident! ; $($e:expr),* $(,)?] => {
+        [$($wrapper!($e)),*]
+    };
+    [$wrapper:path ; $($($e:expr),+ $(,)?);+] => {
+        [$([$($wrapper($e)),+]),+]
+    };
+    [$wrapper:ident! ; $($($e:expr),+ $(,)?);+] => {
+        [$([$($wrapper!($e)),+]),+]
+    };
+    [$($wrapper:ident)::+ { $field:ident, ..$base:expr } ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_list![@update [$($wrapper)::+] $field, $base; $($e),*]
+    };
+    [$($wrapper:ident)::+ { $field:ident, ..$base:expr } => $($rest:tt)*] => {
+        $crate::wrapped_list![$($wrapper)::+ { $field, ..$base } ; $($rest)*]
+    };
+    [@update $wrapper:tt $field:ident, $base:expr; $($e:expr),*] => {
+        [$($crate::wrapped_list![@update $wrapper $field, $base, $e]),*]
+    };
+    [@update [$($wrapper:tt)*] $field:ident, $base:expr, $e:expr] => {
+        $($wrapper)* { $field: $e, ..$base }
+    };
+    [$wrapper:path : $t:ty ;] => {{
+        let list: [$t; 0] = [];
+        list
+    }};
+    [$wrapper:ident! : $t:ty ;] => {{
+        let list: [$t; 0] = [];
+        list
+    }};
+    [derive $wrapper:ty ; len = $len:expr ; $($rest:tt)*] => {{
+        let list: [_; $len] = $crate::wrapped_list![derive $wrapper ; $($rest)*];
+        list
+    }};
+    [derive $wrapper:ty ; $($e:expr),* $(,)?] => {
+        [$(<$wrapper as $crate::Wrap<_>>::wrap($e)),*]
+    };
+    [derive $wrapper:ty ; $($($e:expr),+ $(,)?);+] => {
+        [$([$(<$wrapper as $crate::Wrap<_>>::wrap($e)),+]),+]
+    };
+    [derive $wrapper:ty => $($rest:tt)*] => {
+        $crate::wrapped_list![derive $wrapper ; $($rest)*]
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::wrapped_list![$wrapper ; $($rest)*]
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::wrapped_list![$wrapper! ; $($rest)*]
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$($wrapper:ident)::+ $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [$wrapper:ident! $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_list![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; len = $len:expr ; $($rest:tt)*] => {{
+        let list: [_; $len] = $crate::wrapped_list![@qualified $wrapper ; $($rest)*];
+        list
+    }};
+    [@qualified $wrapper:expr ; $($e:expr),* $(,)?] => {
+        [$($wrapper($e)),*]
+    };
+    [@qualified $wrapper:expr ; $($($e:expr),+ $(,)?);+] => {
+        [$([$($wrapper($e)),+]),+]
+    };
+    [@qualified $wrapper:expr => $($rest:tt)*] => {
+        $crate::wrapped_list![@qualified $wrapper ; $($rest)*]
+    };
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_list![__wrapper! $($rest)*]
+    }}
+}
+
+/// Functions identically to [wrapped_list], but the list is returned as a vector.
+///
+/// Prefix the wrapper with `#newtype` to get a [`WrappedList`] instead.
+///
+/// Prefix the wrapper with `#rev` to emit the elements in reverse order, or
+/// with `#range` to wrap the values of a range of literals.
+///
+/// Prefix the wrapper with `#memo` to only wrap each distinct element once and
+/// clone the result for repeated elements, see [`Memoized`]:
+///
+/// ```
+/// use std::sync::Arc;
+/// use wrapped_list::wrapped_vec;
+///
+/// let names: Vec<Arc<str>> = wrapped_vec![#memo Arc::from; "ada", "bob", "ada"];
+///
+/// assert!(Arc::ptr_eq(&names[0], &names[2]));
+/// ```
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! wrapped_vec {
+    [#newtype $($rest:tt)*] => {
+        $crate::WrappedList::from($crate::wrapped_vec![$($rest)*])
+    };
+    [#compact $($rest:tt)*] => {
+        $crate::__compact![vec; $($rest)*]
+    };
+    [#memo $($rest:tt)*] => {
+        $crate::__memo![$($rest)*]
+    };
+    [#traced $($rest:tt)*] => {
+        $crate::__private::Vec::from($crate::__traced![$($rest)*])
+    };
+    [#rev $($rest:tt)*] => {
+        $crate::__rev![vec [] $($rest)*]
+    };
+    [#range $($rest:tt)*] => {
+        $crate::__range![vec [] $($rest)*]
+    };
+    [$wrapper:path ; $($e:expr),* $(,)?] => {
+        $crate::__private::vec![$($wrapper($e)),*]
+    };
+    [$wrapper:ident! ; $($e:expr),* $(,)?] => {
+        $crate::__private::vec![$($wrapper!($e)),*]
+    };
+    [$wrapper:path : $t:ty ;] => {
+        $crate::__private::Vec::<$t>::new()
+    };
+    [$wrapper:ident! : $t:ty ;] => {
+        $crate::__private::Vec::<$t>::new()
+    };
+    [derive $wrapper:ty ; $($e:expr),* $(,)?] => {
+        $crate::__private::vec![$(<$wrapper as $crate::Wrap<_>>::wrap($e)),*]
+    };
+    [derive $wrapper:ty => $($e:expr),* $(,)?] => {
+        $crate::__private::vec![$(<$wrapper as $crate::Wrap<_>>::wrap($e)),*]
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::wrapped_vec![$wrapper ; $($rest)*]
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::wrapped_vec![$wrapper! ; $($rest)*]
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$($wrapper:ident)::+ $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [$wrapper:ident! $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_vec![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; $($e:expr),* $(,)?] => {
+        $crate::__private::vec![$($wrapper($e)),*]
+    };
+    [@qualified $wrapper:expr => $($e:expr),* $(,)?] => {
+        $crate::__private::vec![$($wrapper($e)),*]
+    };
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_vec![__wrapper! $($rest)*]
+    }}
+}
+
+/// Functions like [wrapped_vec] with a fallible wrapper that returns a
+/// `Result`, stopping at the first error.
+///
+/// Prefix the wrapper type with `derive` to wrap through its [`TryWrap`]
+/// implementation.
+///
+/// Prefix the wrapper with `#all` to wrap every element instead of stopping,
+/// returning `Result<Vec<T>, Vec<(usize, E)>>` with every error and the index
+/// of the element that caused it.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use std::num::NonZeroU32;
+/// use wrapped_list::wrapped_try_vec;
+///
+/// let ports = wrapped_try_vec![str::parse::<u16>; "80", "443"];
+/// assert_eq!(ports, Ok(vec![80, 443]));
+///
+/// let counts = wrapped_try_vec![derive NonZeroU32; 1, 0, 2];
+/// assert!(counts.is_err());
+///
+/// let errors = wrapped_try_vec![#all derive NonZeroU32; 0, 1, 0].unwrap_err();
+/// assert_eq!(errors.iter().map(|&(index, _)| index).collect::<Vec<_>>(), [0, 2]);
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! wrapped_try_vec {
+    [#all derive $wrapper:ty ; $($e:expr),* $(,)?] => {
+        $crate::__private::collect_all([$(<$wrapper as $crate::TryWrap<_>>::try_wrap($e)),*])
+    };
+    [#all derive $wrapper:ty => $($rest:tt)*] => {
+        $crate::wrapped_try_vec![#all derive $wrapper ; $($rest)*]
+    };
+    [#all $($rest:tt)*] => {
+        $crate::__private::collect_all($crate::wrapped_list![$($rest)*])
+    };
+    [$wrapper:path ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_try_vec![@collect $($wrapper($e)),*]
+    };
+    [$wrapper:ident! ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_try_vec![@collect $($wrapper!($e)),*]
+    };
+    [derive $wrapper:ty ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_try_vec![@collect $(<$wrapper as $crate::TryWrap<_>>::try_wrap($e)),*]
+    };
+    [derive $wrapper:ty => $($rest:tt)*] => {
+        $crate::wrapped_try_vec![derive $wrapper ; $($rest)*]
+    };
+    [@collect $($result:expr),*] => {
+        'wrap: {
+            ::core::result::Result::Ok($crate::__private::vec![$(
+                match $result {
+                    ::core::result::Result::Ok(wrapped) => wrapped,
+                    ::core::result::Result::Err(error) => break 'wrap ::core::result::Result::Err(error),
+                }
+            ),*])
+        }
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::wrapped_try_vec![$wrapper ; $($rest)*]
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::wrapped_try_vec![$wrapper! ; $($rest)*]
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$($wrapper:ident)::+ $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [$wrapper:ident! $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_try_vec![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_try_vec![@collect $($wrapper($e)),*]
+    };
+    [@qualified $wrapper:expr => $($e:expr),* $(,)?] => {
+        $crate::wrapped_try_vec![@collect $($wrapper($e)),*]
+    };
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_try_vec![__wrapper! $($rest)*]
+    }}
+}
+
+/// Functions identically to [wrapped_list], but the list is returned as a tuple.
+///
+/// A single element produces a 1-tuple, so `wrapped_tuple!(Some; 1)` is `(Some(1),)`.
+#[macro_export]
+macro_rules! wrapped_tuple {
+    ($wrapper:path ; $($e:expr),* $(,)?) => {
+        ($($wrapper($e),)*)
+    };
+    ($wrapper:ident! ; $($e:expr),* $(,)?) => {
+        ($($wrapper!($e),)*)
+    };
+    (derive $wrapper:ty ; $($e:expr),* $(,)?) => {
+        ($(<$wrapper as $crate::Wrap<_>>::wrap($e),)*)
+    };
+    (derive $wrapper:ty => $($e:expr),* $(,)?) => {
+        ($(<$wrapper as $crate::Wrap<_>>::wrap($e),)*)
+    };
+    (| $($rest:tt)*) => {
+        $crate::__wrapper_error!(closure)
+    };
+    (|| $($rest:tt)*) => {
+        $crate::__wrapper_error!(closure)
+    };
+    (move $($rest:tt)*) => {
+        $crate::__wrapper_error!(closure)
+    };
+    ($wrapper:path => $($rest:tt)*) => {
+        $crate::wrapped_tuple!($wrapper ; $($rest)*)
+    };
+    ($wrapper:ident! => $($rest:tt)*) => {
+        $crate::wrapped_tuple!($wrapper! ; $($rest)*)
+    };
+    ($wrapper:path , $($rest:tt)*) => {
+        $crate::__wrapper_error!(comma)
+    };
+    ($wrapper:ident! , $($rest:tt)*) => {
+        $crate::__wrapper_error!(comma)
+    };
+    ($($wrapper:ident)::+ $e:literal $($rest:tt)*) => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    ($wrapper:ident! $e:literal $($rest:tt)*) => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    (< $($rest:tt)*) => {
+        $crate::wrapped_tuple!(@qualified < $($rest)*)
+    };
+    (@qualified $wrapper:expr ; $($e:expr),* $(,)?) => {
+        ($($wrapper($e),)*)
+    };
+    (@qualified $wrapper:expr => $($e:expr),* $(,)?) => {
+        ($($wrapper($e),)*)
+    };
+    ($($wrapper:ident)::+ ! $($rest:tt)*) => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_tuple![__wrapper! $($rest)*]
+    }}
+}
+
+/// Asserts at compile time that a `const fn` (or macro) holds for every
+/// element of the list.
+///
+/// Expands to one `const _: () = assert!(...);` item per element, so it can be
+/// used anywhere items are allowed.
+///
+/// ```
+/// use wrapped_list::wrapped_const_assert;
+///
+/// const fn is_power_of_two(x: u32) -> bool {
+///     x.is_power_of_two()
+/// }
+///
+/// wrapped_const_assert![is_power_of_two; 1, 2, 4, 8];
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_const_assert;
+///
+/// const fn is_power_of_two(x: u32) -> bool {
+///     x.is_power_of_two()
+/// }
+///
+/// wrapped_const_assert![is_power_of_two; 1, 2, 3];
+/// ```
+#[macro_export]
+macro_rules! wrapped_const_assert {
+    [$wrapper:path ; $($e:expr),* $(,)?] => {
+        $(
+            const _: () = ::core::assert!(
+                $wrapper($e),
+                "{}",
+                ::core::concat!("`", ::core::stringify!($wrapper), "` does not hold for `", ::core::stringify!($e), "`"),
+            );
+        )*
+    };
+    [$wrapper:ident! ; $($e:expr),* $(,)?] => {
+        $crate::wrapped_const_assert![@named ::core::stringify!($wrapper) ; $wrapper! ; $($e),*];
+    };
+    [$($wrapper:ident)::+ ! ; $($e:expr),* $(,)?] => {
+        const _: () = {
+            use $($wrapper)::+ as __wrapper;
+            $crate::wrapped_const_assert![@named ::core::stringify!($($wrapper)::+) ; __wrapper! ; $($e),*];
+        };
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::wrapped_const_assert![$wrapper ; $($rest)*];
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::wrapped_const_assert![$wrapper! ; $($rest)*];
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$($wrapper:ident)::+ $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [$wrapper:ident! $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_const_assert![@qualified < $($rest)*];
+    };
+    [@qualified $wrapper:expr ; $($e:expr),* $(,)?] => {
+        $(
+            const _: () = ::core::assert!(
+                $wrapper($e),
+                "{}",
+                ::core::concat!("`", ::core::stringify!($wrapper), "` does not hold for `", ::core::stringify!($e), "`"),
+            );
+        )*
+    };
+    [@qualified $wrapper:expr => $($e:expr),* $(,)?] => {
+        $crate::wrapped_const_assert![@qualified $wrapper ; $($e),*];
+    };
+    [@named $name:expr ; $wrapper:ident! ; $($e:expr),*] => {
+        $(
+            const _: () = ::core::assert!(
+                $wrapper!($e),
+                "{}",
+                ::core::concat!("`", $name, "!` does not hold for `", ::core::stringify!($e), "`"),
+            );
+        )*
+    }
+}
+
+/// Reports a common mistake in the wrapper position of a macro invocation.
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_list;
+///
+/// // Closures have to be bound to a name first.
+/// let doubled = wrapped_list![|x| x * 2; 1, 2, 3];
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_list;
+///
+/// // The wrapper is separated from the elements with `;`.
+/// let boxed = wrapped_list![Box::new, 1, 2, 3];
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_list;
+///
+/// // The `;` after the wrapper can't be left out.
+/// let options = wrapped_list![Some 1, 2, 3];
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __wrapper_error {
+    (closure) => {
+        ::core::compile_error!(
+            "closures can't be used as wrappers directly; bind the closure to a variable first \
+             (`let f = |x| ...;`) and pass `f` as the wrapper"
+        )
+    };
+    (arrow) => {
+        ::core::compile_error!(
+            "expected `;` after the wrapper, found `=>`; write the map as `wrapper; key => value, ...`"
+        )
+    };
+    (comma) => {
+        ::core::compile_error!(
+            "expected `;` after the wrapper, found `,`; write the list as `wrapper; element, ...`"
+        )
+    };
+    (missing_semicolon) => {
+        ::core::compile_error!(
+            "expected `;` after the wrapper; write the list as `wrapper; element, ...`"
+        )
+    };
+}
+
+/// Parses the wrapper at the start of a macro invocation and passes it on to
+/// `$callback` as a single token tree, so the macros built on it only have to
+/// handle their elements.
+///
+/// The callback is invoked as `$callback![@wrap [$($args)*] $wrapper ...]`,
+/// with everything after the `;` (or `=>`) following the wrapper, which is one
+/// of `(fn $expr)`, `(macro $($ident)::+)` or `(derive $ty)` and is applied
+/// with [__apply_wrapper](crate::__apply_wrapper). In `map` mode the elements
+/// are `key => value` pairs, so `=>` after the wrapper is reported instead of
+/// being accepted in place of `;`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __parse_wrapper {
+    [$mode:ident $callback:ident $args:tt @qualified $wrapper:expr ; $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (fn $wrapper) $($rest)*]
+    };
+    [list $callback:ident $args:tt @qualified $wrapper:expr => $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (fn $wrapper) $($rest)*]
+    };
+    [map $callback:ident $args:tt @qualified $wrapper:expr => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [list $callback:ident $args:tt @wrap $($rest:tt)*] => {
+        ::core::compile_error!("expected the elements after the wrapper, separated by `,`")
+    };
+    [map $callback:ident $args:tt @wrap $($rest:tt)*] => {
+        ::core::compile_error!("expected `key => value` pairs after the wrapper, separated by `,`")
+    };
+    [$mode:ident $callback:ident $args:tt derive $wrapper:ty ; $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (derive $wrapper) $($rest)*]
+    };
+    [list $callback:ident $args:tt derive $wrapper:ty => $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (derive $wrapper) $($rest)*]
+    };
+    [map $callback:ident $args:tt derive $wrapper:ty => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$mode:ident $callback:ident $args:tt | $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$mode:ident $callback:ident $args:tt || $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$mode:ident $callback:ident $args:tt move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$mode:ident $callback:ident $args:tt $wrapper:path ; $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (fn $wrapper) $($rest)*]
+    };
+    [$mode:ident $callback:ident $args:tt $($wrapper:ident)::+ ! ; $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (macro $($wrapper)::+) $($rest)*]
+    };
+    [list $callback:ident $args:tt $wrapper:path => $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (fn $wrapper) $($rest)*]
+    };
+    [list $callback:ident $args:tt $($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::$callback![@wrap $args (macro $($wrapper)::+) $($rest)*]
+    };
+    [map $callback:ident $args:tt $wrapper:path => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [map $callback:ident $args:tt $($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$mode:ident $callback:ident $args:tt $wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$mode:ident $callback:ident $args:tt $($wrapper:ident)::+ ! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$mode:ident $callback:ident $args:tt $($wrapper:ident)::+ $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [$mode:ident $callback:ident $args:tt $($wrapper:ident)::+ ! $e:literal $($rest:tt)*] => {
+        $crate::__wrapper_error!(missing_semicolon)
+    };
+    [$mode:ident $callback:ident $args:tt < $($rest:tt)*] => {
+        $crate::__parse_wrapper![$mode $callback $args @qualified < $($rest)*]
+    };
+}
 
-/// Macro to wrap a list of values with a function, object, or another macro.
-///
-/// See the [examples](crate#examples) to learn more.
+/// Applies a wrapper parsed by [__parse_wrapper](crate::__parse_wrapper) to an
+/// element, or with `@fn`, turns it into something that can be passed to
+/// `map`.
+#[doc(hidden)]
 #[macro_export]
-macro_rules! wrapped_list {
-    [$wrapper:path ; $($e:expr),* $(,)?] => {
-        [$($wrapper($e)),*]
+macro_rules! __apply_wrapper {
+    [(fn $wrapper:expr) $e:expr] => {
+        $wrapper($e)
+    };
+    [(macro $($wrapper:ident)::+) $e:expr] => {
+        $($wrapper)::+!($e)
+    };
+    [(derive $wrapper:ty) $e:expr] => {
+        <$wrapper as $crate::Wrap<_>>::wrap($e)
+    };
+    [@fn (fn $wrapper:expr)] => {
+        $wrapper
+    };
+    [@fn (macro $($wrapper:ident)::+)] => {
+        |element| $($wrapper)::+!(element)
+    };
+    [@fn (derive $wrapper:ty)] => {
+        <$wrapper as $crate::Wrap<_>>::wrap
     };
-    [$wrapper:ident! ; $($e:expr),* $(,)?] => {
-        [$($wrapper!($e)),*]
-    }
 }
 
-/// Functions identically to [wrapped_list], but the list is returned as a vector.
+mod boxed;
+#[cfg(feature = "alloc")]
+mod builder;
+mod cells;
+mod checked;
+#[cfg(feature = "proc-macro")]
+mod chunked;
+mod cli;
+#[cfg(feature = "alloc")]
+mod collections;
+mod compact;
+mod context;
+mod distributed;
+mod enum_iter;
+#[cfg(feature = "alloc")]
+mod fallible;
+#[cfg(feature = "alloc")]
+mod fields;
+mod flags;
+mod for_each;
+mod functions;
+mod group;
+mod handles;
+mod iter;
+mod json;
+mod lazy;
+mod map;
+#[cfg(feature = "alloc")]
+mod memo;
+mod named;
+mod nest;
+mod nonempty;
+mod par;
+mod parsers;
+mod placeholder;
+pub mod prelude;
+mod python;
+mod queries;
+mod range;
+mod rev;
+mod ring;
+mod routes;
+#[cfg(feature = "serde")]
+pub mod serde_wrap;
+mod services;
+mod sized;
+mod strategy;
+mod strings;
+mod systems;
+mod table;
+mod tasks;
+#[cfg(feature = "std")]
+mod testing;
+mod tokens;
+mod trace;
+mod unwrap;
+mod variants;
+mod wasm;
+mod widgets;
+mod wrap;
+
+#[cfg(feature = "alloc")]
+pub use builder::WrappedListBuilder;
+#[cfg(feature = "std")]
+pub use collections::WrappedMap;
+#[cfg(feature = "alloc")]
+pub use collections::{IntoWrapped, WrappedList, WrappedVec};
+#[cfg(feature = "alloc")]
+pub use fields::Fields;
+pub use functions::wrap_array;
+#[cfg(feature = "alloc")]
+pub use functions::wrap_slice;
+pub use iter::{UnwrapWrapped, WrapAll, WrapIter};
+pub use lazy::LazyWrapped;
+#[cfg(feature = "alloc")]
+pub use memo::Memoized;
+#[cfg(feature = "alloc")]
+pub use strings::StringArena;
+pub use unwrap::UnwrapAll;
+pub use variants::Variants;
+#[cfg(feature = "egui")]
+pub use widgets::BoxedWidget;
+pub use wrap::{TryWrap, Wrap};
+
+#[cfg(feature = "phf")]
+pub use phf;
+
+/// Procedural version of [wrapped_list] with span-accurate errors.
+///
+/// Errors point at the offending element instead of the whole invocation, and
+/// any expression can be used as the wrapper. Non-path wrappers, such as
+/// closures, are evaluated once before the elements are wrapped.
+///
+/// Lists with more than 1024 elements are written into an uninitialized array
+/// a few elements at a time instead of being expanded as a single array
+/// literal, so generated inputs with tens of thousands of elements still
+/// compile in reasonable time and don't build a huge temporary on the stack.
+/// These lists can't be used in `const` contexts.
+/// Only this macro does this: `wrapped_list!`, `wrapped_vec!`, and their
+/// `#compact` and `#rev` forms always expand to a single array literal.
+///
+/// Prefix the wrapper with `#unique` to reject duplicate literal elements at
+/// compile time, for ID lists, opcode sets, and other tables where a duplicate
+/// is always a bug. Literals are compared by value, so `10` and `0xa`, `0`
+/// and `-0`, or `1.0` and `1e0` are duplicates, and elements that aren't
+/// literals are never compared. The `#unique` modifier of [wrapped_list] uses
+/// this macro.
+///
+/// Requires the `proc-macro` feature.
+///
+/// ```
+/// use wrapped_list::wrapped;
+///
+/// let doubled = wrapped![|x| x * 2; 1, 2, 3];
+/// let ids = wrapped![#unique Some; 1, 2, 3];
+///
+/// assert_eq!(doubled, [2, 4, 6]);
+/// assert_eq!(ids, [Some(1), Some(2), Some(3)]);
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped;
+///
+/// let ids = wrapped![#unique Some; 1, 2, 0x1];
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped;
+///
+/// let weights = wrapped![#unique Some; 0.5, 1.0, 1e0];
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped;
+///
+/// let offsets = wrapped![#unique Some; 0, -0];
+/// ```
+#[cfg(feature = "proc-macro")]
+pub use wrapped_list_macros::wrapped;
+
+/// Expands the `#unique` modifier of [wrapped_list] with [wrapped].
+#[cfg(feature = "proc-macro")]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! wrapped_vec {
-    [$wrapper:path ; $($e:expr),* $(,)?] => {
-        vec![$($wrapper($e)),*]
+macro_rules! __unique {
+    [$($rest:tt)*] => {
+        $crate::wrapped![#unique $($rest)*]
     };
-    [$wrapper:ident! ; $($e:expr),* $(,)?] => {
-        vec![$($wrapper!($e)),*]
-    }
 }
 
-/// Functions identically to [wrapped_list], but the list is returned as a tuple.
+/// Reports that the `#unique` modifier of [wrapped_list] needs the
+/// `proc-macro` feature.
+#[cfg(not(feature = "proc-macro"))]
+#[doc(hidden)]
 #[macro_export]
-macro_rules! wrapped_tuple {
-    ($wrapper:path ; $($e:expr),* $(,)?) => {
-        ($($wrapper($e)),*)
+macro_rules! __unique {
+    [$($rest:tt)*] => {
+        ::core::compile_error!("the `#unique` modifier requires the `proc-macro` feature of wrapped-list")
     };
-    ($wrapper:ident! ; $($e:expr),* $(,)?) => {
-        ($($wrapper!($e)),*)
-    }
+}
+
+/// Parses a list of date literals at compile time and expands to an array of
+/// [`time::Date`]s, or of [`time::PrimitiveDateTime`]s when the literals
+/// include a time of day.
+///
+/// Dates are written as `YYYY-MM-DD`, optionally followed by ` HH:MM:SS` or
+/// `THH:MM:SS`. A malformed or impossible date fails the build with an error
+/// pointing at its literal.
+///
+/// Requires the `time` feature.
+///
+/// ```
+/// use time::{Date, Month};
+/// use wrapped_list::date_list;
+///
+/// const HOLIDAYS: [Date; 2] = date_list!["2024-01-01", "2024-12-25"];
+///
+/// assert_eq!(HOLIDAYS[1].month(), Month::December);
+///
+/// let meetings = date_list!["2024-06-30 09:30:00", "2024-07-01T14:00:00"];
+/// assert_eq!(meetings[0].hour(), 9);
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::date_list;
+///
+/// let dates = date_list!["2023-02-29"];
+/// ```
+#[cfg(feature = "time")]
+pub use wrapped_list_macros::date_list;
+
+/// Checks a list of regex patterns at compile time and expands to an array of
+/// [`LazyLock<Regex>`](std::sync::LazyLock)s, each compiled on first use.
+///
+/// An invalid pattern fails the build with an error pointing at its literal,
+/// so malformed patterns in static lists never reach runtime.
+///
+/// Requires the `regex` feature.
+///
+/// ```
+/// use std::sync::LazyLock;
+/// use regex::Regex;
+/// use wrapped_list::regex_list;
+///
+/// static PATTERNS: [LazyLock<Regex>; 2] = regex_list!["^a+$", "[0-9]{3}"];
+///
+/// assert!(PATTERNS[0].is_match("aaa"));
+/// assert!(PATTERNS[1].is_match("x123"));
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::regex_list;
+///
+/// let patterns = regex_list!["(unclosed"];
+/// ```
+#[cfg(feature = "regex")]
+pub use wrapped_list_macros::regex_list;
+
+/// Wraps every element of the array literal assigned to a `static` or `const`
+/// item, keeping large tables readable.
+///
+/// Requires the `proc-macro` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_array;
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Opcode(u8);
+///
+/// #[wrapped_array(Opcode)]
+/// static OPCODES: [Opcode; 3] = [0x01, 0x02, 0x03];
+///
+/// assert_eq!(OPCODES[1], Opcode(0x02));
+/// ```
+#[cfg(feature = "proc-macro")]
+pub use wrapped_list_macros::wrapped_array;
+
+/// Lets a function accept plain values for array or collection parameters
+/// marked with `#[wrap(T)]`, wrapping every element before the body runs.
+///
+/// Arrays are wrapped with `array::map`, any other collection with a single
+/// type parameter, such as `Vec<W>`, is rebuilt with `collect`.
+///
+/// Requires the `proc-macro` feature.
+///
+/// ```
+/// use wrapped_list::wrap_args;
+///
+/// #[wrap_args(Box::new)]
+/// fn store(#[wrap(i32)] items: [Box<i32>; 3]) -> Vec<Box<i32>> {
+///     items.into()
+/// }
+///
+/// assert_eq!(store([1, 2, 3]), [Box::new(1), Box::new(2), Box::new(3)]);
+/// ```
+#[cfg(feature = "proc-macro")]
+pub use wrapped_list_macros::wrap_args;
+
+/// Adds a wrapped copy of a `static` item to a registry declared with
+/// [wrapped_distributed_slice](crate::wrapped_distributed_slice), from this
+/// crate or any other one linked into the final binary.
+///
+/// The `static` is kept as written. The registry entry applies the wrapper to
+/// a fresh value built from the same initializer every time the registry is
+/// gathered with [wrapped_collect](crate::wrapped_collect), so the wrapper can
+/// take the value by ownership, as `Box::new` does.
+///
+/// Requires the `linkme` feature.
+///
+/// ```
+/// use wrapped_list::{wrapped_collect, wrapped_distributed_slice, wrapped_submit};
+///
+/// wrapped_distributed_slice! {
+///     static GREETINGS: [String];
+/// }
+///
+/// #[wrapped_submit(GREETINGS, String::from)]
+/// static HELLO: &str = "hello";
+///
+/// assert_eq!(wrapped_collect!(GREETINGS), ["hello"]);
+/// ```
+#[cfg(feature = "linkme")]
+pub use wrapped_list_macros::wrapped_submit;
+
+/// Derives [`Wrap`] for single-field structs and for enums whose variants
+/// each have a single field.
+///
+/// Requires the `proc-macro` feature.
+#[cfg(feature = "proc-macro")]
+pub use wrapped_list_macros::Wrap;
+
+/// Derives [`Fields`] for structs.
+///
+/// Requires the `proc-macro` and `alloc` features.
+#[cfg(all(feature = "proc-macro", feature = "alloc"))]
+pub use wrapped_list_macros::Fields;
+
+/// Derives [`Variants`] for fieldless enums.
+///
+/// Requires the `proc-macro` feature.
+#[cfg(feature = "proc-macro")]
+pub use wrapped_list_macros::Variants;
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "alloc")]
+    pub use crate::boxed::{boxed_array, push_with};
+    #[cfg(feature = "proc-macro")]
+    pub use crate::chunked::ChunkedArray;
+    #[cfg(feature = "linkme")]
+    pub use crate::distributed::collect_submitted;
+    #[cfg(feature = "strum")]
+    pub use crate::enum_iter::all_variants;
+    #[cfg(feature = "alloc")]
+    pub use crate::fallible::{collect_all, partition};
+    pub use crate::group::{chunks, group_count, windows};
+    #[cfg(feature = "vec1")]
+    pub use crate::nonempty::vec1_from_array;
+    pub use crate::range::{is_char_range, range_len, range_values, RangeValue};
+    #[cfg(feature = "ringbuffer")]
+    pub use crate::ring::ring_buffer;
+    #[cfg(feature = "bevy")]
+    pub use crate::systems::boxed_system;
+    #[cfg(feature = "std")]
+    pub use crate::testing::assert_all_failed;
+    #[cfg(feature = "egui")]
+    pub use crate::widgets::boxed_widget;
+    #[cfg(feature = "alloc")]
+    pub use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+    #[cfg(feature = "std")]
+    pub use std::{
+        collections::HashMap,
+        sync::{LazyLock, OnceLock},
+    };
+    #[cfg(feature = "proc-macro")]
+    pub use wrapped_list_macros::unique_keys;
+
+    #[cfg(feature = "anyhow")]
+    pub use anyhow;
+    #[cfg(feature = "axum")]
+    pub use axum;
+    #[cfg(feature = "bitvec")]
+    pub use bitvec;
+    #[cfg(feature = "criterion")]
+    pub use criterion;
+    #[cfg(feature = "dashmap")]
+    pub use dashmap;
+    #[cfg(feature = "futures")]
+    pub use futures;
+    #[cfg(feature = "generic-array")]
+    pub use generic_array;
+    #[cfg(feature = "iced")]
+    pub use iced_core;
+    #[cfg(feature = "linkme")]
+    pub use linkme;
+    #[cfg(feature = "nom")]
+    pub use nom;
+    #[cfg(feature = "phf")]
+    pub use phf;
+    #[cfg(feature = "priority-queue")]
+    pub use priority_queue;
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+    #[cfg(feature = "pyo3")]
+    pub use pyo3;
+    #[cfg(feature = "quote")]
+    pub use quote;
+    #[cfg(feature = "rayon")]
+    pub use rayon;
+    #[cfg(feature = "regex")]
+    pub use regex;
+    #[cfg(feature = "serde_json")]
+    pub use serde_json;
+    #[cfg(feature = "slab")]
+    pub use slab;
+    #[cfg(feature = "slotmap")]
+    pub use slotmap;
+    #[cfg(feature = "strum")]
+    pub use strum;
+    #[cfg(feature = "time")]
+    pub use time;
+    #[cfg(feature = "tokio")]
+    pub use tokio;
+    #[cfg(feature = "tower")]
+    pub use tower;
+    #[cfg(feature = "tracing")]
+    pub use tracing;
+    #[cfg(feature = "wasm-bindgen")]
+    pub use {js_sys, wasm_bindgen};
 }
 
 #[doc(hidden)]
 #[cfg(test)]
 mod tests {
     use duplicate::duplicate_item;
+    use std::boxed::Box;
+    #[cfg(feature = "alloc")]
+    use std::vec;
 
     #[derive(PartialEq, Eq, Debug)]
     struct Wrapper(i32);
@@ -178,6 +1577,7 @@ mod tests {
         [wrapper_function1]    [vec_function_test1];
         [wrapper_function2]    [vec_function_test2];
     )]
+    #[cfg(feature = "alloc")]
     #[test]
     fn test_name() {
         let my_list = vec![wrapper(1)];
@@ -200,7 +1600,7 @@ mod tests {
     )]
     #[test]
     fn test_name() {
-        let my_list = wrapper(1);
+        let my_list = (wrapper(1),);
         assert_eq!(my_list, wrapped_tuple!(wrapper; 1));
         let my_list = (wrapper(1), wrapper(2));
         assert_eq!(my_list, wrapped_tuple!(wrapper; 1, 2));
@@ -210,6 +1610,172 @@ mod tests {
         assert_eq!(my_list, wrapped_tuple!(wrapper; 1, 2, 3, 4));
     }
 
+    #[test]
+    fn tuple_macro() {
+        let my_list = (wrapper_macro1!(1),);
+        assert_eq!(my_list, wrapped_tuple!(wrapper_macro1!; 1));
+        let my_list = (wrapper_macro1!(1), wrapper_macro1!(2));
+        assert_eq!(my_list, wrapped_tuple!(wrapper_macro1!; 1, 2,));
+    }
+
+    #[test]
+    fn arrow_separator() {
+        let my_list = [Wrapper(1), Wrapper(2)];
+        assert_eq!(my_list, wrapped_list![Wrapper => 1, 2]);
+        let my_list = [wrapper_macro2!(1), wrapper_macro2!(2)];
+        assert_eq!(my_list, wrapped_list![wrapper_macro2! => 1, 2,]);
+        let my_list = [10, 20];
+        assert_eq!(my_list, wrapped_list![wrappers::times_ten! => 1, 2]);
+        let my_list = (Wrapper(1), Wrapper(2));
+        assert_eq!(my_list, wrapped_tuple!(Wrapper => 1, 2));
+        let my_list: [Wrapper; 0] = wrapped_list![Wrapper =>];
+        assert_eq!(my_list, []);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_arrow_separator() {
+        let my_list = vec![Wrapper(1), Wrapper(2)];
+        assert_eq!(my_list, wrapped_vec![Wrapper => 1, 2]);
+    }
+
+    wrapped_const_assert![is_even => 2, 4];
+
+    #[test]
+    fn delimiters() {
+        let my_list = [Wrapper(1), Wrapper(2)];
+        assert_eq!(my_list, wrapped_list!(Wrapper; 1, 2));
+        assert_eq!(my_list, wrapped_list![Wrapper; 1, 2]);
+        assert_eq!(my_list, wrapped_list! { Wrapper; 1, 2 });
+        let my_list = (Wrapper(1), Wrapper(2));
+        assert_eq!(my_list, wrapped_tuple!(Wrapper; 1, 2));
+        assert_eq!(my_list, wrapped_tuple![Wrapper; 1, 2]);
+        assert_eq!(my_list, wrapped_tuple! { Wrapper; 1, 2 });
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_delimiters() {
+        let my_list = vec![Wrapper(1), Wrapper(2)];
+        assert_eq!(my_list, wrapped_vec!(Wrapper; 1, 2));
+        assert_eq!(my_list, wrapped_vec![Wrapper; 1, 2]);
+        assert_eq!(my_list, wrapped_vec! { Wrapper; 1, 2 });
+    }
+
+    const fn r#const(input: i32) -> bool {
+        input > 0
+    }
+
+    wrapped_const_assert![r#const; 1, 2];
+    wrapped_const_assert!(is_even; 2);
+    wrapped_const_assert! { is_even; 4 }
+
+    fn r#box(input: i32) -> Wrapper {
+        Wrapper(input)
+    }
+
+    mod r#try {
+        pub fn new(input: i32) -> super::Wrapper {
+            super::Wrapper(input)
+        }
+
+        macro_rules! r#loop {
+            ($e:expr) => {
+                $e * 10
+            };
+        }
+
+        pub(crate) use r#loop;
+    }
+
+    macro_rules! r#match {
+        ($e:expr) => {
+            $e + 1
+        };
+    }
+
+    #[test]
+    fn raw_identifiers() {
+        let my_list = [Wrapper(1), Wrapper(2)];
+        assert_eq!(my_list, wrapped_list![r#box; 1, 2]);
+        assert_eq!(my_list, wrapped_list![r#try::new; 1, 2]);
+        assert_eq!(my_list, wrapped_list![self::r#try::new => 1, 2]);
+        let my_list = [2, 3];
+        assert_eq!(my_list, wrapped_list![r#match!; 1, 2]);
+        let my_list = [10, 20];
+        assert_eq!(my_list, wrapped_list![r#try::r#loop!; 1, 2]);
+        let my_list = (Wrapper(1), Wrapper(2));
+        assert_eq!(my_list, wrapped_tuple!(r#try::new; 1, 2));
+    }
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Borrowed<'a>(&'a str);
+
+    impl<'a> From<&'a str> for Borrowed<'a> {
+        fn from(input: &'a str) -> Self {
+            Borrowed(input)
+        }
+    }
+
+    fn parse<'a, T: From<&'a str>>(input: &'a str) -> T {
+        T::from(input)
+    }
+
+    #[test]
+    fn generic_wrappers() {
+        let my_list = [Borrowed("a"), Borrowed("b")];
+        assert_eq!(my_list, wrapped_list![parse::<'static, Borrowed>; "a", "b"]);
+        assert_eq!(my_list, wrapped_list![Borrowed::<'static>; "a", "b"]);
+        assert_eq!(
+            my_list,
+            wrapped_list![<Borrowed as From<&str>>::from; "a", "b"]
+        );
+        assert_eq!(
+            my_list,
+            wrapped_list![<Borrowed<'static>>::from => "a", "b"]
+        );
+        let my_list = (Borrowed("a"), Borrowed("b"));
+        assert_eq!(
+            my_list,
+            wrapped_tuple!(<Borrowed as From<&str>>::from; "a", "b")
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_generic_wrappers() {
+        let my_list = vec![Borrowed("a"), Borrowed("b")];
+        assert_eq!(my_list, wrapped_vec![parse::<'static, Borrowed>; "a", "b"]);
+        assert_eq!(
+            my_list,
+            wrapped_vec![<Borrowed as From<&str>>::from; "a", "b"]
+        );
+    }
+
+    wrapped_const_assert![<i32>::is_positive; 1, 2];
+
+    #[test]
+    fn empty_lists() {
+        let my_list: [Wrapper; 0] = wrapped_list![Wrapper;];
+        assert_eq!(my_list, []);
+        let my_list = wrapped_list![Wrapper: Wrapper;];
+        assert_eq!(my_list, []);
+        let my_list = wrapped_list![wrapper_macro1!: Wrapper;];
+        assert_eq!(my_list, []);
+        assert_eq!(wrapped_tuple!(Wrapper;), ());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn empty_vecs() {
+        let my_list: vec::Vec<Wrapper> = wrapped_vec![Wrapper;];
+        assert!(my_list.is_empty());
+        let my_list = wrapped_vec![Wrapper: Wrapper;];
+        assert_eq!(my_list, vec::Vec::<Wrapper>::new());
+        let my_list = wrapped_vec![wrapper_macro1!: Wrapper;];
+        assert_eq!(my_list, vec::Vec::<Wrapper>::new());
+    }
+
     #[test]
     fn trailing_commas() {
         let my_list = [Wrapper(1)];
@@ -244,6 +1810,423 @@ mod tests {
         assert_eq!(my_list, wrapped_list!(macro_name!; 1, 2, 3, 4));
     }
 
+    mod wrappers {
+        macro_rules! times_ten {
+            ($e:expr) => {
+                $e * 10
+            };
+        }
+
+        macro_rules! is_positive {
+            ($e:expr) => {
+                $e > 0
+            };
+        }
+
+        pub(crate) use {is_positive, times_ten};
+    }
+
+    #[test]
+    fn macro_path() {
+        let my_list = [10, 20, 30];
+        assert_eq!(my_list, wrapped_list![wrappers::times_ten!; 1, 2, 3]);
+        assert_eq!(my_list, wrapped_list![self::wrappers::times_ten!; 1, 2, 3,]);
+        let my_list = (10, 20);
+        assert_eq!(
+            my_list,
+            wrapped_tuple!(crate::tests::wrappers::times_ten!; 1, 2)
+        );
+        let my_list: [i32; 0] = wrapped_list![wrappers::times_ten!: i32;];
+        assert_eq!(my_list, [] as [i32; 0]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn vec_macro_path() {
+        let my_list = vec![10, 20, 30];
+        assert_eq!(my_list, wrapped_vec![wrappers::times_ten!; 1, 2, 3]);
+    }
+
+    wrapped_const_assert![wrappers::is_positive!; 1, 2];
+
+    const fn is_even(input: i32) -> bool {
+        input % 2 == 0
+    }
+
+    macro_rules! is_small {
+        ($e:expr) => {
+            $e < 10
+        };
+    }
+
+    wrapped_const_assert![is_even; 2, 4, 6, 8,];
+    wrapped_const_assert![is_small!; 1, 2, 3];
+
+    const fn non_empty(input: &str) -> bool {
+        !input.is_empty()
+    }
+
+    macro_rules! is_non_empty {
+        ($e:expr) => {
+            !$e.is_empty()
+        };
+    }
+
+    wrapped_const_assert![non_empty; "{}", "{x}", "}"];
+    wrapped_const_assert![is_non_empty!; "{}"];
+
+    #[test]
+    fn const_list() {
+        let table: &'static [Wrapper; 3] = &wrapped_list![#const Wrapper; 1, 2, 3];
+        assert_eq!(table, &[Wrapper(1), Wrapper(2), Wrapper(3)]);
+        let table: &'static [bool; 2] = &wrapped_list![#const is_even => 1, 2];
+        assert_eq!(table, &[false, true]);
+        let table: &'static [bool; 2] = &wrapped_list![#const wrappers::is_positive!; -1, 1];
+        assert_eq!(table, &[false, true]);
+        let empty: &'static [Wrapper; 0] = &wrapped_list![#const Wrapper: Wrapper;];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn nested() {
+        macro_rules! double {
+            ($e:expr) => {
+                $e * 2
+            };
+        }
+
+        assert_eq!(wrapped_list![Some ^ 3; 1], [Some(Some(Some(1)))]);
+        assert_eq!(wrapped_list![Option::Some ^ 1 => 1, 2], [Some(1), Some(2)]);
+        assert_eq!(wrapped_list![double! ^ 8; 1, 2], [256, 512]);
+        let empty: [Option<Option<i32>>; 0] = wrapped_list![Some ^ 2;];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn struct_update() {
+        #[derive(Debug, PartialEq)]
+        struct Task {
+            name: &'static str,
+            payload: i32,
+        }
+
+        const BASE: Task = Task {
+            name: "task",
+            payload: 0,
+        };
+
+        assert_eq!(
+            wrapped_list![Task { payload, ..BASE }; 1, 2],
+            [
+                Task {
+                    name: "task",
+                    payload: 1
+                },
+                Task {
+                    name: "task",
+                    payload: 2
+                }
+            ]
+        );
+        let [task] = wrapped_list![Task { name, ..BASE } => "a"];
+        assert_eq!(task.name, "a");
+    }
+
+    #[test]
+    fn reversed() {
+        assert_eq!(
+            wrapped_list![#rev Wrapper; 1, 2, 3,],
+            [Wrapper(3), Wrapper(2), Wrapper(1)]
+        );
+        assert_eq!(wrapped_list![#rev wrappers::times_ten! => 1, 2], [20, 10]);
+        assert_eq!(wrapped_list![#rev #compact <i64>::from; 1i32, 2i32], [2, 1]);
+        assert_eq!(
+            wrapped_list![#rev Some; 1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+            [10, 9, 8, 7, 6, 5, 4, 3, 2, 1].map(Some)
+        );
+        let empty: [Wrapper; 0] = wrapped_list![#rev Wrapper;];
+        assert!(empty.is_empty());
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            wrapped_vec![#rev derive core::num::Wrapping<u8> => 1, 2],
+            [core::num::Wrapping(2), core::num::Wrapping(1)]
+        );
+    }
+
+    #[test]
+    fn expected_len() {
+        assert_eq!(
+            wrapped_list![Wrapper; len = 2; 1, 2],
+            [Wrapper(1), Wrapper(2)]
+        );
+        assert_eq!(wrapped_list![wrappers::times_ten! => len = 1; 1], [10]);
+        assert_eq!(
+            wrapped_list![derive core::num::Wrapping<u8>; len = 1 + 1; 1, 2],
+            [core::num::Wrapping(1), core::num::Wrapping(2)]
+        );
+        assert_eq!(wrapped_list![<i64>::from; len = 1; 1i32], [1]);
+        let empty: [Wrapper; 0] = wrapped_list![Wrapper; len = 0;];
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn groups() {
+        assert_eq!(
+            wrapped_list![Wrapper; 1, 2; 3, 4,; 5, 6],
+            [
+                [Wrapper(1), Wrapper(2)],
+                [Wrapper(3), Wrapper(4)],
+                [Wrapper(5), Wrapper(6)]
+            ]
+        );
+        assert_eq!(wrapped_list![wrappers::times_ten! => 1; 2], [[10], [20]]);
+        assert_eq!(
+            wrapped_list![derive core::num::Wrapping<u8>; 1; 2],
+            [[core::num::Wrapping(1)], [core::num::Wrapping(2)]]
+        );
+        assert_eq!(wrapped_list![<i64>::from => 1i32; 2i32], [[1], [2]]);
+    }
+
+    #[test]
+    fn placeholder() {
+        #[derive(Debug, PartialEq)]
+        struct Entry(u8, &'static str, u8);
+
+        assert_eq!(
+            wrapped_list![Entry(0, _, 1 + 1); "a", "b"],
+            [Entry(0, "a", 2), Entry(0, "b", 2)]
+        );
+        assert_eq!(wrapped_list![Entry(_, "", 0,) => 1], [Entry(1, "", 0)]);
+        assert_eq!(wrapped_list![core::cmp::max(_, 2); 1, 3], [2, 3]);
+    }
+
+    #[test]
+    fn unsafe_wrapper() {
+        unsafe fn first(values: &[u8]) -> u8 {
+            unsafe { *values.get_unchecked(0) }
+        }
+
+        macro_rules! first {
+            ($e:expr) => {
+                first($e)
+            };
+        }
+
+        assert_eq!(wrapped_list![unsafe first; &[1], &[2, 3]], [1, 2]);
+        assert_eq!(wrapped_list![unsafe first! => &[4]], [4]);
+        assert_eq!(
+            wrapped_list![unsafe core::str::from_utf8_unchecked; b"ab"],
+            ["ab"]
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn shadowed_vec() {
+        #[allow(unused_macros)]
+        macro_rules! vec {
+            ($($t:tt)*) => {
+                compile_error!("the caller's `vec!` was used")
+            };
+        }
+
+        let my_list = ::std::vec![Wrapper(1), Wrapper(2)];
+        assert_eq!(my_list, wrapped_vec![Wrapper; 1, 2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn try_vec() {
+        use std::num::NonZeroU8;
+
+        fn checked(x: i32) -> Result<Wrapper, i32> {
+            if x > 0 {
+                Ok(Wrapper(x))
+            } else {
+                Err(x)
+            }
+        }
+
+        macro_rules! checked {
+            ($e:expr) => {
+                checked($e)
+            };
+        }
+
+        assert_eq!(
+            wrapped_try_vec![checked; 1, 2],
+            Ok(vec![Wrapper(1), Wrapper(2)])
+        );
+        assert_eq!(wrapped_try_vec![checked => 1, -2, -3], Err(-2));
+        assert_eq!(wrapped_try_vec![checked!; 3], Ok(vec![Wrapper(3)]));
+        assert_eq!(
+            wrapped_try_vec![derive NonZeroU8; 1],
+            Ok(vec![NonZeroU8::MIN])
+        );
+        assert!(wrapped_try_vec![derive NonZeroU8 => 1, 0].is_err());
+        assert_eq!(
+            wrapped_try_vec![#all checked; 1, -2, 3, -4],
+            Err(vec![(1, -2), (3, -4)])
+        );
+        assert_eq!(
+            wrapped_try_vec![#all checked! => 1, 2],
+            Ok(vec![Wrapper(1), Wrapper(2)])
+        );
+        assert_eq!(
+            wrapped_try_vec![#all derive NonZeroU8 => 0, 1]
+                .unwrap_err()
+                .len(),
+            1
+        );
+        assert_eq!(
+            wrapped_try_vec![<u8 as TryFrom<i32>>::try_from; 1],
+            Ok(vec![1u8])
+        );
+    }
+
+    #[cfg(feature = "proc-macro")]
+    mod proc_macro {
+        use super::{wrapper_function1, wrappers, Borrowed, Wrapper};
+        use crate::wrapped;
+
+        #[test]
+        fn wrapped() {
+            let my_list = [Wrapper(1), Wrapper(2)];
+            assert_eq!(my_list, wrapped![Wrapper; 1, 2]);
+            assert_eq!(my_list, wrapped![Wrapper => 1, 2,]);
+            assert_eq!(my_list, wrapped![wrapper_macro1!; 1, 2]);
+            let my_list = [10, 20];
+            assert_eq!(my_list, wrapped![wrapper_function1; 1, 2]);
+            assert_eq!(my_list, wrapped![wrappers::times_ten!; 1, 2]);
+            let my_list = [Borrowed("a"), Borrowed("b")];
+            assert_eq!(my_list, wrapped![<Borrowed as From<&str>>::from; "a", "b"]);
+            let my_list: [Wrapper; 0] = wrapped![Wrapper;];
+            assert_eq!(my_list, []);
+        }
+
+        #[test]
+        fn unique() {
+            let x = 1;
+            assert_eq!(
+                wrapped![#unique Wrapper; 1, 2, -1, x, x],
+                [1, 2, -1, 1, 1].map(Wrapper)
+            );
+            assert_eq!(
+                wrapped_list![#unique Some => "a", "b"],
+                [Some("a"), Some("b")]
+            );
+            assert_eq!(
+                wrapped![#unique Some; 0.5, -0.5, 1.5],
+                [Some(0.5), Some(-0.5), Some(1.5)]
+            );
+        }
+
+        #[crate::wrapped_array(Wrapper)]
+        static STATIC_LIST: [Wrapper; 2] = [1, 2];
+
+        #[crate::wrapped_array(wrapper_macro1!)]
+        const CONST_LIST: [Wrapper; 3] = [1, 2, 3];
+
+        #[test]
+        fn wrapped_array() {
+            assert_eq!(STATIC_LIST, [Wrapper(1), Wrapper(2)]);
+            assert_eq!(CONST_LIST, [Wrapper(1), Wrapper(2), Wrapper(3)]);
+        }
+
+        #[crate::wrap_args(Wrapper)]
+        fn sum(#[wrap(i32)] items: [Wrapper; 3], offset: i32) -> i32 {
+            items.iter().map(|w| w.0).sum::<i32>() + offset
+        }
+
+        #[crate::wrap_args(wrapper_macro1!)]
+        fn collect(#[wrap(i32)] items: std::vec::Vec<Wrapper>) -> std::vec::Vec<Wrapper> {
+            items
+        }
+
+        #[crate::wrap_args(Wrapper)]
+        fn extend(#[wrap(i32)] mut items: std::vec::Vec<Wrapper>) -> std::vec::Vec<Wrapper> {
+            items.push(Wrapper(0));
+            items
+        }
+
+        #[test]
+        fn wrap_args() {
+            assert_eq!(sum([1, 2, 3], 10), 16);
+            assert_eq!(collect(std::vec![1, 2]), [Wrapper(1), Wrapper(2)]);
+            assert_eq!(extend(std::vec![1]), [Wrapper(1), Wrapper(0)]);
+        }
+
+        #[test]
+        fn wrapped_expression() {
+            let mut calls = 0;
+            let my_list = wrapped![{ calls += 1; |x| x + calls }; 1, 2, 3];
+            assert_eq!(my_list, [2, 3, 4]);
+            assert_eq!(calls, 1);
+        }
+
+        /// Repeats the element tokens according to each factor, then wraps them.
+        macro_rules! repeated {
+            ([] $($element:tt)*) => {
+                wrapped![Wrapper; $($element)*]
+            };
+            ([x2 $($factor:ident)*] $($element:tt)*) => {
+                repeated!([$($factor)*] $($element)* $($element)*)
+            };
+            ([x5 $($factor:ident)*] $($element:tt)*) => {
+                repeated!([$($factor)*] $($element)* $($element)* $($element)* $($element)* $($element)*)
+            };
+        }
+
+        #[test]
+        fn wrapped_large() {
+            let mut count = 0;
+            let mut next = || {
+                count += 1;
+                count
+            };
+            let list: [Wrapper; 50_000] = repeated!([x2 x2 x2 x2 x5 x5 x5 x5 x5] next(),);
+            assert!(list.iter().zip(1..).all(|(item, i)| item.0 == i));
+        }
+
+        #[cfg(feature = "time")]
+        #[test]
+        fn date_list() {
+            use time::{Date, Month, PrimitiveDateTime, Time};
+
+            let dates = crate::date_list!["2024-02-29", "1999-12-31",];
+            assert_eq!(
+                dates,
+                [
+                    Date::from_calendar_date(2024, Month::February, 29).unwrap(),
+                    Date::from_calendar_date(1999, Month::December, 31).unwrap(),
+                ]
+            );
+
+            let [date_time] = crate::date_list!["2000-01-02T03:04:05"];
+            assert_eq!(
+                date_time,
+                PrimitiveDateTime::new(
+                    Date::from_calendar_date(2000, Month::January, 2).unwrap(),
+                    Time::from_hms(3, 4, 5).unwrap()
+                )
+            );
+
+            let empty: [Date; 0] = crate::date_list![];
+            assert!(empty.is_empty());
+        }
+
+        #[cfg(feature = "regex")]
+        #[test]
+        fn regex_list() {
+            let patterns = crate::regex_list![r"^\d+$", "b+",];
+            assert!(patterns[0].is_match("123"));
+            assert!(!patterns[0].is_match("12a"));
+            assert_eq!(patterns[1].find("abbc").unwrap().as_str(), "bb");
+            let empty: [std::sync::LazyLock<regex::Regex>; 0] = crate::regex_list![];
+            assert!(empty.is_empty());
+        }
+    }
+
     #[test]
     fn trailing_commas_macro() {
         let my_list = [wrapper_macro2!(1)];
@@ -0,0 +1,603 @@
+/// Builds a compile-time [`phf::Map`] whose values are wrapped with a
+/// function, object, or another macro.
+///
+/// Keys must be literals supported by `phf`, and the wrapped values must be
+/// usable in a `static` initializer.
+///
+/// ```
+/// use wrapped_list::{phf, wrapped_phf_map};
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// struct Keyword(u8);
+///
+/// static KEYWORDS: phf::Map<&'static str, Keyword> = wrapped_phf_map![Keyword; "if" => 1, "else" => 2];
+///
+/// assert_eq!(KEYWORDS.get("else"), Some(&Keyword(2)));
+/// ```
+#[cfg(feature = "phf")]
+#[macro_export]
+macro_rules! wrapped_phf_map {
+    [$wrapper:path ; $($k:literal => $v:expr),* $(,)?] => {{
+        use $crate::__private::phf;
+        phf::phf_map! { $($k => $wrapper($v)),* }
+    }};
+    [$wrapper:ident! ; $($k:literal => $v:expr),* $(,)?] => {{
+        use $crate::__private::phf;
+        phf::phf_map! { $($k => $wrapper!($v)),* }
+    }};
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_phf_map![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; $($k:literal => $v:expr),* $(,)?] => {{
+        use $crate::__private::phf;
+        phf::phf_map! { $($k => $wrapper($v)),* }
+    }};
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_phf_map![__wrapper! $($rest)*]
+    }}
+}
+
+/// Builds a [`HashMap`](std::collections::HashMap) whose values are wrapped
+/// with a function, object, or another macro.
+///
+/// When every key is a literal, duplicate keys are rejected at compile time
+/// instead of silently overwriting the earlier entry. With the `proc-macro`
+/// feature the error reads "duplicate key"; without it, the repeated key is
+/// reported as an unreachable pattern by the `unreachable_patterns` lint.
+///
+/// Prefix the wrapper with `#newtype` to get a [`WrappedMap`](crate::WrappedMap)
+/// instead.
+///
+/// Requires the `std` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_hashmap;
+///
+/// let map = wrapped_hashmap![Box::new; "a" => 1, "b" => 2];
+///
+/// assert_eq!(map["a"], Box::new(1));
+/// assert_eq!(map["b"], Box::new(2));
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_hashmap;
+///
+/// let map = wrapped_hashmap![Box::new; "a" => 1, "a" => 2];
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! wrapped_hashmap {
+    [#newtype $($rest:tt)*] => {
+        $crate::WrappedMap::from($crate::wrapped_hashmap![$($rest)*])
+    };
+    [$wrapper:path ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::__private::HashMap::from([$(($k, $wrapper($v))),*])
+    }};
+    [$wrapper:ident! ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::__private::HashMap::from([$(($k, $wrapper!($v))),*])
+    }};
+    [$wrapper:path ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::__private::HashMap::from([$(($k, $wrapper($v))),*])
+    };
+    [$wrapper:ident! ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::__private::HashMap::from([$(($k, $wrapper!($v))),*])
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_hashmap![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::__private::HashMap::from([$(($k, $wrapper($v))),*])
+    }};
+    [@qualified $wrapper:expr ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::__private::HashMap::from([$(($k, $wrapper($v))),*])
+    };
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_hashmap![__wrapper! $($rest)*]
+    }}
+}
+
+/// Builds a [`BTreeMap`](alloc::collections::BTreeMap) whose values are
+/// wrapped, with the same duplicate key checks as `wrapped_hashmap!`.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! wrapped_btreemap {
+    [$wrapper:path ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::__private::BTreeMap::from([$(($k, $wrapper($v))),*])
+    }};
+    [$wrapper:ident! ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::__private::BTreeMap::from([$(($k, $wrapper!($v))),*])
+    }};
+    [$wrapper:path ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::__private::BTreeMap::from([$(($k, $wrapper($v))),*])
+    };
+    [$wrapper:ident! ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::__private::BTreeMap::from([$(($k, $wrapper!($v))),*])
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_btreemap![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::__private::BTreeMap::from([$(($k, $wrapper($v))),*])
+    }};
+    [@qualified $wrapper:expr ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::__private::BTreeMap::from([$(($k, $wrapper($v))),*])
+    };
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_btreemap![__wrapper! $($rest)*]
+    }}
+}
+
+/// Builds a [`DashMap`](dashmap::DashMap) whose values are wrapped, with the
+/// same duplicate key checks as `wrapped_hashmap!`.
+///
+/// Requires the `dashmap` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_dashmap;
+///
+/// let registry = wrapped_dashmap![String::from; 1 => "alpha", 2 => "beta"];
+///
+/// registry.insert(3, String::from("gamma"));
+/// assert_eq!(*registry.get(&1).unwrap(), "alpha");
+/// assert_eq!(registry.len(), 3);
+/// ```
+#[cfg(feature = "dashmap")]
+#[macro_export]
+macro_rules! wrapped_dashmap {
+    [$wrapper:path ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::wrapped_dashmap![@collect $(($k, $wrapper($v))),*]
+    }};
+    [$wrapper:ident! ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::wrapped_dashmap![@collect $(($k, $wrapper!($v))),*]
+    }};
+    [$wrapper:path ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::wrapped_dashmap![@collect $(($k, $wrapper($v))),*]
+    };
+    [$wrapper:ident! ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::wrapped_dashmap![@collect $(($k, $wrapper!($v))),*]
+    };
+    [@collect $($entry:expr),*] => {
+        <$crate::__private::dashmap::DashMap<_, _> as ::core::iter::FromIterator<_>>::from_iter([
+            $($entry),*
+        ])
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_dashmap![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::wrapped_dashmap![@collect $(($k, $wrapper($v))),*]
+    }};
+    [@qualified $wrapper:expr ; $($k:expr => $v:expr),* $(,)?] => {
+        $crate::wrapped_dashmap![@collect $(($k, $wrapper($v))),*]
+    };
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_dashmap![__wrapper! $($rest)*]
+    }}
+}
+
+/// Builds a [`PriorityQueue`](priority_queue::PriorityQueue) from
+/// `item => priority` pairs, wrapping the items.
+///
+/// Like `PriorityQueue::push`, an item that appears more than once keeps the
+/// priority of its last occurrence.
+///
+/// Requires the `priority-queue` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_priority_queue;
+///
+/// let mut jobs = wrapped_priority_queue![String::from; "backup" => 1, "deploy" => 5];
+///
+/// assert_eq!(jobs.pop(), Some((String::from("deploy"), 5)));
+/// assert_eq!(jobs.pop(), Some((String::from("backup"), 1)));
+/// ```
+#[cfg(feature = "priority-queue")]
+#[macro_export]
+macro_rules! wrapped_priority_queue {
+    [$wrapper:path ; $($item:expr => $priority:expr),* $(,)?] => {
+        $crate::wrapped_priority_queue![@collect $(($wrapper($item), $priority)),*]
+    };
+    [$wrapper:ident! ; $($item:expr => $priority:expr),* $(,)?] => {
+        $crate::wrapped_priority_queue![@collect $(($wrapper!($item), $priority)),*]
+    };
+    [@collect $($entry:expr),*] => {
+        <$crate::__private::priority_queue::PriorityQueue<_, _> as ::core::iter::FromIterator<_>>::from_iter([
+            $($entry),*
+        ])
+    };
+    [| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [|| $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [move $($rest:tt)*] => {
+        $crate::__wrapper_error!(closure)
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::__wrapper_error!(arrow)
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [$wrapper:ident! , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+    [< $($rest:tt)*] => {
+        $crate::wrapped_priority_queue![@qualified < $($rest)*]
+    };
+    [@qualified $wrapper:expr ; $($item:expr => $priority:expr),* $(,)?] => {
+        $crate::wrapped_priority_queue![@collect $(($wrapper($item), $priority)),*]
+    };
+    [$($wrapper:ident)::+ ! $($rest:tt)*] => {{
+        #[allow(unused_imports)]
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_priority_queue![__wrapper! $($rest)*]
+    }}
+}
+
+/// Defines `static` registries: [`HashMap`](std::collections::HashMap)s with
+/// wrapped values, built on first use through a
+/// [`LazyLock`](std::sync::LazyLock).
+///
+/// The list after `=` takes the same wrappers as
+/// [wrapped_list](crate::wrapped_list) followed by `key => value` entries. Each
+/// wrapped value is coerced to the value type, so boxed trait objects of
+/// different types can share a registry. Duplicate literal keys are rejected at
+/// compile time.
+///
+/// Requires the `std` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_registry;
+///
+/// trait Handler: Sync + Send {
+///     fn handle(&self) -> u16;
+/// }
+///
+/// struct Get;
+/// struct Put;
+///
+/// impl Handler for Get {
+///     fn handle(&self) -> u16 {
+///         200
+///     }
+/// }
+///
+/// impl Handler for Put {
+///     fn handle(&self) -> u16 {
+///         201
+///     }
+/// }
+///
+/// wrapped_registry! {
+///     static HANDLERS: &str => Box<dyn Handler> = [Box::new; "get" => Get, "put" => Put];
+/// }
+///
+/// assert_eq!(HANDLERS["put"].handle(), 201);
+/// ```
+#[cfg(feature = "std")]
+#[macro_export]
+macro_rules! wrapped_registry {
+    {$(
+        $(#[$attr:meta])*
+        $vis:vis static $name:ident : $k:ty => $v:ty = [$($list:tt)*];
+    )*} => {$(
+        $(#[$attr])*
+        $vis static $name: $crate::__private::LazyLock<$crate::__private::HashMap<$k, $v>> =
+            $crate::__private::LazyLock::new(|| $crate::wrapped_registry!(@entries $v; [] $($list)*));
+    )*};
+    (@entries $v:ty; [$($wrapper:tt)*] ; $($key:literal => $value:expr),* $(,)?) => {{
+        $crate::__unique_keys!($($key),*);
+        $crate::wrapped_registry!(@map $v; [$($wrapper)*] $($key => $value),*)
+    }};
+    (@entries $v:ty; [$($wrapper:tt)*] ; $($key:expr => $value:expr),* $(,)?) => {
+        $crate::wrapped_registry!(@map $v; [$($wrapper)*] $($key => $value),*)
+    };
+    (@entries $v:ty; [$($wrapper:tt)*]) => {
+        ::core::compile_error!("expected `;` followed by `key => value` entries")
+    };
+    (@entries $v:ty; [$($wrapper:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::wrapped_registry!(@entries $v; [$($wrapper)* $next] $($rest)*)
+    };
+    (@map $v:ty; $wrapper:tt $($key:expr => $value:expr),*) => {
+        $crate::__private::HashMap::from([$(($key, $crate::wrapped_registry!(@value $v; $wrapper $value))),*])
+    };
+    (@value $v:ty; [$($wrapper:tt)*] $value:expr) => {{
+        let [value] = $crate::wrapped_list![$($wrapper)* ; $value];
+        let value: $v = value;
+        value
+    }};
+}
+
+/// Fails compilation with a "duplicate key" error if any of the given
+/// literals have the same value, using the same comparison as the `#unique`
+/// modifier of [wrapped](crate::wrapped).
+#[cfg(all(feature = "alloc", feature = "proc-macro"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unique_keys {
+    ($($k:literal),*) => {
+        $crate::__private::unique_keys!($($k),*);
+    };
+}
+
+/// Fails compilation if any of the given literals appear more than once.
+///
+/// Without the `proc-macro` feature, every literal becomes a match arm, so a
+/// duplicate is reported by the `unreachable_patterns` lint pointing at the
+/// repeated key.
+#[cfg(all(feature = "alloc", not(feature = "proc-macro")))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __unique_keys {
+    () => {};
+    ($first:literal $(, $k:literal)*) => {
+        #[deny(unreachable_patterns)]
+        let _ = || match $first {
+            $first => {}
+            $($k => {})*
+            #[allow(unreachable_patterns)]
+            _ => {}
+        };
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use duplicate::duplicate_item;
+    use std::collections::{BTreeMap, HashMap};
+    use std::prelude::rust_2021::*;
+
+    #[derive(PartialEq, Eq, Hash, Debug)]
+    struct Wrapper(i32);
+
+    impl Wrapper {
+        fn new(input: i32) -> Self {
+            Wrapper(input)
+        }
+    }
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e * 10)
+        };
+    }
+
+    mod wrappers {
+        macro_rules! wrap_twice {
+            ($e:expr) => {
+                $e * 2
+            };
+        }
+
+        pub(crate) use wrap_twice;
+    }
+
+    const KEY_A: &str = "a";
+    const KEY_B: &str = "b";
+
+    #[duplicate_item(
+        map_macro          map_type    test_name;
+        [wrapped_hashmap]  [HashMap]   [hashmap_test];
+        [wrapped_btreemap] [BTreeMap]  [btreemap_test];
+    )]
+    #[test]
+    fn test_name() {
+        let map: map_type<&str, Wrapper> = map_macro![Wrapper;];
+        assert!(map.is_empty());
+        let map = map_macro![Wrapper; "a" => 1, "b" => 2,];
+        assert_eq!(map, map_type::from([("a", Wrapper(1)), ("b", Wrapper(2))]));
+        assert_eq!(map, map_macro!(Wrapper; "a" => 1, "b" => 2));
+        assert_eq!(map, map_macro! { Wrapper; "a" => 1, "b" => 2 });
+        let map = map_macro![wrapper_macro!; 1 => 1, 2 => 2];
+        assert_eq!(map, map_type::from([(1, Wrapper(10)), (2, Wrapper(20))]));
+        let map = map_macro![wrappers::wrap_twice!; 1 => 1, 2 => 2];
+        assert_eq!(map, map_type::from([(1, 2), (2, 4)]));
+        let map = map_macro![Wrapper; KEY_A => 1, KEY_B => 2];
+        assert_eq!(map, map_type::from([("a", Wrapper(1)), ("b", Wrapper(2))]));
+        let map = map_macro![<Wrapper>::new; "a" => 1, "b" => 2];
+        assert_eq!(map, map_type::from([("a", Wrapper(1)), ("b", Wrapper(2))]));
+        let map = map_macro![<Wrapper>::new; KEY_A => 1];
+        assert_eq!(map, map_type::from([("a", Wrapper(1))]));
+        let map = map_macro![Wrapper; KEY_A => 1, KEY_A => 2];
+        assert_eq!(map, map_type::from([("a", Wrapper(2))]));
+    }
+
+    #[cfg(feature = "dashmap")]
+    #[test]
+    fn dashmap() {
+        use dashmap::DashMap;
+
+        let map: DashMap<&str, Wrapper> = wrapped_dashmap![Wrapper;];
+        assert!(map.is_empty());
+        let map = wrapped_dashmap![Wrapper; "a" => 1, "b" => 2,];
+        assert_eq!(*map.get("a").unwrap(), Wrapper(1));
+        assert_eq!(*map.get("b").unwrap(), Wrapper(2));
+        let map = wrapped_dashmap![wrapper_macro!; KEY_A => 1];
+        assert_eq!(*map.get("a").unwrap(), Wrapper(10));
+        let map = wrapped_dashmap![<Wrapper>::new; 1 => 1, 2 => 2];
+        assert_eq!(map.len(), 2);
+        let map = wrapped_dashmap![wrappers::wrap_twice!; 1 => 1];
+        assert_eq!(*map.get(&1).unwrap(), 2);
+    }
+
+    #[cfg(feature = "priority-queue")]
+    #[test]
+    fn priority_queue() {
+        use priority_queue::PriorityQueue;
+
+        let queue: PriorityQueue<Wrapper, u8> = wrapped_priority_queue![Wrapper;];
+        assert!(queue.is_empty());
+        let mut queue = wrapped_priority_queue![Wrapper; 1 => 2, 2 => 3, 3 => 1,];
+        assert_eq!(queue.pop(), Some((Wrapper(2), 3)));
+        assert_eq!(queue.peek(), Some((&Wrapper(1), &2)));
+        let queue = wrapped_priority_queue![wrapper_macro!; 1 => 1, 1 => 4];
+        assert_eq!(queue.into_sorted_vec(), [Wrapper(10)]);
+        let queue = wrapped_priority_queue![<Wrapper>::new; 1 => 'a'];
+        assert_eq!(queue.get_priority(&Wrapper(1)), Some(&'a'));
+        let queue = wrapped_priority_queue![wrappers::wrap_twice!; 1 => 1];
+        assert_eq!(queue.peek(), Some((&2, &1)));
+    }
+
+    mod registry {
+        use std::boxed::Box;
+        use std::fmt::Debug;
+
+        const KEY: u8 = 3;
+
+        wrapped_registry! {
+            static WRAPPERS: &str => super::Wrapper = [super::Wrapper::new; "a" => 1, "b" => 2,];
+
+            /// Mixed value types behind a trait object.
+            pub(super) static DEBUG: u8 => Box<dyn Debug + Sync + Send> = [Box::new;
+                1 => 1,
+                2 => "two",
+                KEY => super::Wrapper(3),
+            ];
+            static MACROS: i32 => i32 = [super::wrappers::wrap_twice!;];
+        }
+
+        #[test]
+        fn registry() {
+            assert_eq!(WRAPPERS.len(), 2);
+            assert_eq!(WRAPPERS["b"], super::Wrapper(2));
+            assert_eq!(std::format!("{:?}", DEBUG[&2]), "\"two\"");
+            assert_eq!(std::format!("{:?}", DEBUG[&3]), "Wrapper(3)");
+            assert!(MACROS.is_empty());
+        }
+    }
+
+    #[cfg(feature = "phf")]
+    mod phf {
+        use super::Wrapper;
+        use crate::phf::Map;
+
+        static MAP: Map<&'static str, Wrapper> = wrapped_phf_map![Wrapper; "a" => 1, "b" => 2,];
+        static MACRO_MAP: Map<u32, Wrapper> =
+            wrapped_phf_map![wrapper_macro!; 1u32 => 1, 2u32 => 2];
+        static PATH_MAP: Map<u32, i32> = wrapped_phf_map![super::wrappers::wrap_twice!; 1u32 => 1];
+
+        #[test]
+        fn phf_map() {
+            assert_eq!(MAP.len(), 2);
+            assert_eq!(MAP.get("a"), Some(&Wrapper(1)));
+            assert_eq!(MAP.get("b"), Some(&Wrapper(2)));
+            assert_eq!(MAP.get("c"), None);
+        }
+
+        #[test]
+        fn phf_map_macro() {
+            assert_eq!(MACRO_MAP.get(&1), Some(&Wrapper(10)));
+            assert_eq!(MACRO_MAP.get(&2), Some(&Wrapper(20)));
+            assert_eq!(PATH_MAP.get(&1), Some(&2));
+        }
+    }
+}
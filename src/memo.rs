@@ -0,0 +1,127 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+/// Wrapper that caches its results, so equal inputs are only wrapped once.
+///
+/// Every later call with an equal input returns a clone of the cached result,
+/// which is useful for interning, such as turning repeated strings into shared
+/// `Arc<str>`s. Used by the `#memo` modifier of [wrapped_vec](crate::wrapped_vec).
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use std::sync::Arc;
+/// use wrapped_list::Memoized;
+///
+/// let mut intern = Memoized::new(Arc::<str>::from);
+/// let a = intern.wrap("a");
+///
+/// assert!(Arc::ptr_eq(&a, &intern.wrap("a")));
+/// assert_eq!(intern.len(), 1);
+/// ```
+pub struct Memoized<T, W, F> {
+    cache: BTreeMap<T, W>,
+    wrapper: F,
+}
+
+impl<T, W, F> Memoized<T, W, F>
+where
+    T: Ord + Clone,
+    W: Clone,
+    F: FnMut(T) -> W,
+{
+    /// Creates an empty cache in front of `wrapper`.
+    pub fn new(wrapper: F) -> Self {
+        Memoized {
+            cache: BTreeMap::new(),
+            wrapper,
+        }
+    }
+
+    /// Returns the wrapped `value`, only calling the wrapper if no equal value
+    /// was wrapped before.
+    pub fn wrap(&mut self, value: T) -> W {
+        let wrapper = &mut self.wrapper;
+        self.cache
+            .entry(value)
+            .or_insert_with_key(|value| wrapper(value.clone()))
+            .clone()
+    }
+
+    /// Wraps every value in order and collects the results.
+    pub fn wrap_all<I: IntoIterator<Item = T>>(&mut self, values: I) -> Vec<W> {
+        values.into_iter().map(|value| self.wrap(value)).collect()
+    }
+}
+
+impl<T, W, F> Memoized<T, W, F> {
+    /// Returns the number of distinct values wrapped so far.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns `true` if nothing has been wrapped yet.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+}
+
+/// Expands to a `Vec` of wrapped values, wrapping each distinct element only
+/// once through a [`Memoized`] cache.
+///
+/// Used by the `#memo` modifier of [wrapped_vec](crate::wrapped_vec).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __memo {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::Memoized::new($crate::__apply_wrapper![@fn $wrapper]).wrap_all([$($e),*])
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list __memo [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::Memoized;
+    use std::rc::Rc;
+    use std::vec::Vec;
+
+    #[test]
+    fn memoized() {
+        let mut calls = 0;
+        let mut memo = Memoized::new(|value: i32| {
+            calls += 1;
+            value * 10
+        });
+        assert!(memo.is_empty());
+        assert_eq!(memo.wrap(1), 10);
+        assert_eq!(memo.wrap(2), 20);
+        assert_eq!(memo.wrap(1), 10);
+        assert_eq!(memo.len(), 2);
+        assert_eq!(memo.wrap_all([2, 3]), [20, 30]);
+        drop(memo);
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    fn memo_vec() {
+        let list = wrapped_vec![#memo Rc::<str>::from; "a", "b", "a"];
+        assert_eq!(list, ["a".into(), "b".into(), "a".into()] as [Rc<str>; 3]);
+        assert!(Rc::ptr_eq(&list[0], &list[2]));
+
+        macro_rules! shared {
+            ($e:expr) => {
+                Rc::new($e)
+            };
+        }
+
+        let list = wrapped_vec![#memo shared! => 1, 1];
+        assert!(Rc::ptr_eq(&list[0], &list[1]));
+        let list = wrapped_vec![#memo <Rc<i32>>::new; 1, 2, 1];
+        assert_eq!(Rc::strong_count(&list[0]), 2);
+        let empty: Vec<Rc<i32>> = wrapped_vec![#memo Rc::new;];
+        assert!(empty.is_empty());
+    }
+}
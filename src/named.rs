@@ -0,0 +1,52 @@
+/// Functions like [wrapped_list](crate::wrapped_list), but pairs every wrapped
+/// value with the source text of its element, as given by `stringify!`.
+///
+/// Useful for table-driven tests and debug dumps, where a failing case should
+/// be reported by the expression that produced it.
+///
+/// ```
+/// use wrapped_list::named_list;
+///
+/// let x = 3;
+/// let cases = named_list![Some; x + 1, x * 2];
+///
+/// assert_eq!(cases, [("x + 1", Some(4)), ("x * 2", Some(6))]);
+/// ```
+#[macro_export]
+macro_rules! named_list {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        [$((::core::stringify!($e), $crate::__apply_wrapper![$wrapper $e])),*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list named_list [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use core::num::Wrapping;
+
+    macro_rules! double {
+        ($e:expr) => {
+            $e * 2
+        };
+    }
+
+    #[test]
+    fn named_list() {
+        let a = 1;
+        assert_eq!(
+            named_list![Some; a + 1, a * 3,],
+            [("a + 1", Some(2)), ("a * 3", Some(3))]
+        );
+        assert_eq!(named_list![double! => a], [("a", 2)]);
+        assert_eq!(
+            named_list![derive Wrapping<u8>; 1u8],
+            [("1u8", Wrapping(1))]
+        );
+        assert_eq!(named_list![<i64>::from; 1i32], [("1i32", 1)]);
+        let empty: [(&str, Option<u8>); 0] = named_list![Some;];
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,34 @@
+/// Applies a wrapper to an element `depth` times, innermost call first.
+///
+/// Used by the `^` depth syntax of [wrapped_list](crate::wrapped_list).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __nest {
+    [[$($wrapper:tt)*] 1 $e:expr] => {
+        $($wrapper)*($e)
+    };
+    [[$($wrapper:tt)*] 2 $e:expr] => {
+        $($wrapper)*($crate::__nest!([$($wrapper)*] 1 $e))
+    };
+    [[$($wrapper:tt)*] 3 $e:expr] => {
+        $($wrapper)*($crate::__nest!([$($wrapper)*] 2 $e))
+    };
+    [[$($wrapper:tt)*] 4 $e:expr] => {
+        $($wrapper)*($crate::__nest!([$($wrapper)*] 3 $e))
+    };
+    [[$($wrapper:tt)*] 5 $e:expr] => {
+        $($wrapper)*($crate::__nest!([$($wrapper)*] 4 $e))
+    };
+    [[$($wrapper:tt)*] 6 $e:expr] => {
+        $($wrapper)*($crate::__nest!([$($wrapper)*] 5 $e))
+    };
+    [[$($wrapper:tt)*] 7 $e:expr] => {
+        $($wrapper)*($crate::__nest!([$($wrapper)*] 6 $e))
+    };
+    [[$($wrapper:tt)*] 8 $e:expr] => {
+        $($wrapper)*($crate::__nest!([$($wrapper)*] 7 $e))
+    };
+    [$wrapper:tt $depth:tt $e:expr] => {
+        ::core::compile_error!("the nesting depth must be an integer literal from 1 to 8")
+    };
+}
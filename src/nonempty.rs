@@ -0,0 +1,67 @@
+#[cfg(feature = "vec1")]
+use vec1::Vec1;
+
+/// Functions identically to [wrapped_vec](crate::wrapped_vec), but returns a
+/// [`Vec1`](vec1::Vec1), a `Vec` that is guaranteed to hold at least one
+/// element.
+///
+/// An empty list fails the build instead of panicking at runtime.
+///
+/// Requires the `vec1` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_vec1;
+///
+/// let servers = wrapped_vec1![String::from; "primary", "fallback"];
+///
+/// assert_eq!(servers.first(), "primary");
+/// ```
+///
+/// ```compile_fail
+/// use vec1::Vec1;
+/// use wrapped_list::wrapped_vec1;
+///
+/// let servers: Vec1<String> = wrapped_vec1![String::from;];
+/// ```
+#[cfg(feature = "vec1")]
+#[macro_export]
+macro_rules! wrapped_vec1 {
+    [$($rest:tt)*] => {
+        $crate::__private::vec1_from_array($crate::wrapped_list![$($rest)*])
+    };
+}
+
+/// Converts `items` into a `Vec1`, failing the build if the array is empty.
+#[cfg(feature = "vec1")]
+#[doc(hidden)]
+pub fn vec1_from_array<T, const N: usize>(items: [T; N]) -> Vec1<T> {
+    const { assert!(N > 0, "a `Vec1` needs at least one element") };
+    match Vec1::try_from_vec(items.into()) {
+        Ok(items) => items,
+        Err(_) => unreachable!(),
+    }
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "vec1"))]
+mod tests {
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn vec1() {
+        let items = wrapped_vec1![Wrapper; 1, 2];
+        assert_eq!(items.len_nonzero().get(), 2);
+        assert_eq!(items.last(), &Wrapper(2));
+        let items = wrapped_vec1![wrapper_macro! => 3];
+        assert_eq!(items.first(), &Wrapper(3));
+        let items = wrapped_vec1![derive Option<_>; 4];
+        assert_eq!(items.into_vec(), [Some(4)]);
+    }
+}
@@ -0,0 +1,71 @@
+/// Functions identically to [wrapped_vec](crate::wrapped_vec), but the
+/// wrapper is applied to the elements in parallel on the `rayon` thread pool.
+///
+/// This pays off for expensive wrappers such as compression, hashing, or
+/// decoding. The order of the elements is preserved.
+///
+/// Requires the `rayon` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_par_vec;
+///
+/// fn checksum(data: &[u8]) -> u32 {
+///     data.iter().map(|&b| u32::from(b)).sum()
+/// }
+///
+/// let sums = wrapped_par_vec![checksum; b"abc", b"xyz"];
+///
+/// assert_eq!(sums, [294, 363]);
+/// ```
+#[cfg(feature = "rayon")]
+#[macro_export]
+macro_rules! wrapped_par_vec {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {{
+        use $crate::__private::rayon::iter::{IntoParallelIterator, ParallelIterator};
+        [$($e),*]
+            .into_par_iter()
+            .map(|item| $crate::__apply_wrapper![$wrapper item])
+            .collect::<$crate::__private::Vec<_>>()
+    }};
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_par_vec [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "rayon"))]
+mod tests {
+    use std::boxed::Box;
+    use std::string::String;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(u64);
+
+    fn slow_square(x: u64) -> Wrapper {
+        Wrapper((0..x).map(|_| x).sum())
+    }
+
+    macro_rules! square {
+        ($e:expr) => {
+            slow_square($e)
+        };
+    }
+
+    #[test]
+    fn par_vec() {
+        assert_eq!(
+            wrapped_par_vec![slow_square; 1, 2, 3],
+            [Wrapper(1), Wrapper(4), Wrapper(9)]
+        );
+        assert_eq!(wrapped_par_vec![square! => 4], [Wrapper(16)]);
+        assert_eq!(
+            wrapped_par_vec![derive Box<_>; 1, 2],
+            [Box::new(1), Box::new(2)]
+        );
+        assert_eq!(
+            wrapped_par_vec![<String as From<&str>>::from; "a", "b"],
+            ["a", "b"]
+        );
+        assert!(wrapped_par_vec![Wrapper;].is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+/// Combines a list of nom parsers into a single [`alt`](nom::branch::alt),
+/// which tries them in order and returns the result of the first that
+/// succeeds.
+///
+/// With a wrapper, such as `tag` or `preceded(space0, _)`, every element is
+/// wrapped as with [wrapped_list](crate::wrapped_list) and the resulting array
+/// is passed to `alt`, so the list can be as long as needed. Without one, the
+/// elements can be parsers of different types; they are grouped into nested
+/// tuples, so lists longer than the 21 parsers a single `alt` tuple accepts
+/// still work.
+///
+/// Requires the `nom` feature.
+///
+/// ```
+/// use nom::bytes::complete::tag;
+/// use nom::character::complete::digit1;
+/// use nom::combinator::value;
+/// use nom::{IResult, Parser};
+/// use wrapped_list::wrapped_alt;
+///
+/// fn keyword(input: &str) -> IResult<&str, &str> {
+///     wrapped_alt![tag; "let", "fn", "if", "else"].parse(input)
+/// }
+///
+/// fn token(input: &str) -> IResult<&str, &str> {
+///     wrapped_alt![keyword, value("number", digit1)].parse(input)
+/// }
+///
+/// assert_eq!(keyword("fn main"), Ok((" main", "fn")));
+/// assert_eq!(token("42;"), Ok((";", "number")));
+/// assert!(token("while").is_err());
+/// ```
+#[cfg(feature = "nom")]
+#[macro_export]
+macro_rules! wrapped_alt {
+    [@tuple
+        $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr, $e8:expr,
+        $e9:expr, $e10:expr, $e11:expr, $e12:expr, $e13:expr, $e14:expr, $e15:expr,
+        $e16:expr, $e17:expr, $e18:expr, $e19:expr, $e20:expr, $($rest:expr),+
+    ] => {
+        $crate::__private::nom::branch::alt((
+            $e1, $e2, $e3, $e4, $e5, $e6, $e7, $e8, $e9, $e10, $e11, $e12, $e13, $e14, $e15,
+            $e16, $e17, $e18, $e19, $e20, $crate::wrapped_alt![@tuple $($rest),+],
+        ))
+    };
+    [@tuple $e:expr] => {
+        $e
+    };
+    [@tuple $($e:expr),+] => {
+        $crate::__private::nom::branch::alt(($($e),+))
+    };
+    [$wrapper:ident! ; $($rest:tt)*] => {
+        $crate::__private::nom::branch::alt($crate::wrapped_list![$wrapper! ; $($rest)*])
+    };
+    [$wrapper:ident! => $($rest:tt)*] => {
+        $crate::__private::nom::branch::alt($crate::wrapped_list![$wrapper! ; $($rest)*])
+    };
+    [$($wrapper:ident)::+ ! ; $($rest:tt)*] => {
+        $crate::__private::nom::branch::alt($crate::wrapped_list![$($wrapper)::+ ! ; $($rest)*])
+    };
+    [$($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::__private::nom::branch::alt($crate::wrapped_list![$($wrapper)::+ ! ; $($rest)*])
+    };
+    [] => {
+        ::core::compile_error!("expected at least one parser")
+    };
+    [$($e:expr),+ $(,)?] => {
+        $crate::wrapped_alt![@tuple $($e),+]
+    };
+    [$($rest:tt)*] => {
+        $crate::__private::nom::branch::alt($crate::wrapped_list![$($rest)*])
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "nom"))]
+mod tests {
+    use nom::bytes::complete::tag;
+    use nom::character::complete::{alpha1, digit1, space0};
+    use nom::combinator::value;
+    use nom::sequence::preceded;
+    use nom::{IResult, Parser};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Op {
+        Add,
+        Sub,
+        Other,
+    }
+
+    macro_rules! keyword {
+        ($e:expr) => {
+            tag($e)
+        };
+    }
+
+    fn op(input: &str) -> IResult<&str, Op> {
+        wrapped_alt![value(Op::Add, tag("+")), value(Op::Sub, tag("-"))].parse(input)
+    }
+
+    fn parse<'a, O>(
+        mut parser: impl Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+        input: &'a str,
+    ) -> IResult<&'a str, O> {
+        parser.parse(input)
+    }
+
+    #[test]
+    fn alt() {
+        assert_eq!(op("-1"), Ok(("1", Op::Sub)));
+        let spaced = wrapped_alt![preceded(space0, _); tag("a"), tag("b")];
+        assert_eq!(parse(spaced, "  b"), Ok(("", "b")));
+        let keywords = wrapped_alt![keyword! => "in", "out"];
+        assert_eq!(parse(keywords, "out"), Ok(("", "out")));
+        assert_eq!(parse(wrapped_alt![digit1], "7a"), Ok(("a", "7")));
+        assert_eq!(parse(wrapped_alt![digit1, alpha1,], "ab1"), Ok(("1", "ab")));
+    }
+
+    #[test]
+    fn long_alt() {
+        fn long(input: &str) -> IResult<&str, Op> {
+            wrapped_alt![
+                value(Op::Other, tag("a")),
+                value(Op::Other, tag("b")),
+                value(Op::Other, tag("c")),
+                value(Op::Other, tag("d")),
+                value(Op::Other, tag("e")),
+                value(Op::Other, tag("f")),
+                value(Op::Other, tag("g")),
+                value(Op::Other, tag("h")),
+                value(Op::Other, tag("i")),
+                value(Op::Other, tag("j")),
+                value(Op::Other, tag("k")),
+                value(Op::Other, tag("l")),
+                value(Op::Other, tag("m")),
+                value(Op::Other, tag("n")),
+                value(Op::Other, tag("o")),
+                value(Op::Other, tag("p")),
+                value(Op::Other, tag("q")),
+                value(Op::Other, tag("r")),
+                value(Op::Other, tag("s")),
+                value(Op::Other, tag("t")),
+                value(Op::Other, tag("u")),
+                value(Op::Other, tag("v")),
+                value(Op::Add, tag("+")),
+            ]
+            .parse(input)
+        }
+
+        assert_eq!(long("+"), Ok(("", Op::Add)));
+        assert_eq!(long("v"), Ok(("", Op::Other)));
+        assert!(long("z").is_err());
+    }
+}
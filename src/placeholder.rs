@@ -0,0 +1,29 @@
+/// Calls a wrapper with its arguments, replacing the `_` placeholder argument
+/// with the element.
+///
+/// Used by the placeholder wrapper form of [wrapped_list](crate::wrapped_list).
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __placeholder {
+    [@arg $wrapper:tt $e:tt [$($done:tt)*] _ $(, $($rest:tt)*)?] => {
+        $crate::__placeholder![@filled $wrapper [$($done)* $e,] $($($rest)*)?]
+    };
+    [@arg $wrapper:tt $e:tt [$($done:tt)*] $arg:expr $(, $($rest:tt)*)?] => {
+        $crate::__placeholder![@arg $wrapper $e [$($done)* $arg,] $($($rest)*)?]
+    };
+    [@arg $wrapper:tt $e:tt [$($done:tt)*]] => {
+        ::core::compile_error!("the wrapper arguments need a `_` placeholder for the element")
+    };
+    [@filled $wrapper:tt [$($done:tt)*] _ $(, $($rest:tt)*)?] => {
+        ::core::compile_error!("the wrapper arguments can only have one `_` placeholder")
+    };
+    [@filled $wrapper:tt [$($done:tt)*] $arg:expr $(, $($rest:tt)*)?] => {
+        $crate::__placeholder![@filled $wrapper [$($done)* $arg,] $($($rest)*)?]
+    };
+    [@filled [$($wrapper:tt)*] [$($done:tt)*]] => {
+        $($wrapper)*($($done)*)
+    };
+    [$wrapper:tt ($($args:tt)*) $e:tt] => {
+        $crate::__placeholder![@arg $wrapper $e [] $($args)*]
+    };
+}
@@ -0,0 +1,155 @@
+//! Everything needed to use the crate with a single import.
+//!
+//! ```
+//! use wrapped_list::prelude::*;
+//!
+//! let boxed = wrapped_list![Box::new; 1, 2];
+//! let doubled: Vec<i32> = wrapped_iter![Box::new; 1, 2].map(|b| *b * 2).collect();
+//!
+//! assert_eq!(boxed.unwrap_all_with(|b| *b), [1, 2]);
+//! assert_eq!(doubled, [2, 4]);
+//! ```
+
+pub use crate::{
+    atomics_list, checked_list, cstr_list, lazy_wrapped_list, named_list, unwrapped_list,
+    wrap_array, wrapped_chunks, wrapped_const_assert, wrapped_flags, wrapped_flatten,
+    wrapped_for_each, wrapped_interleave, wrapped_iter, wrapped_list, wrapped_table,
+    wrapped_transpose, wrapped_tuple, wrapped_variants, wrapped_windows, LazyWrapped, TryWrap,
+    UnwrapAll, UnwrapWrapped, Variants, Wrap, WrapAll,
+};
+
+#[cfg(feature = "alloc")]
+pub use crate::{
+    string_list, wrap_slice, wrapped_boxed_array, wrapped_btreemap, wrapped_fields,
+    wrapped_partition, wrapped_try_vec, wrapped_vec, Fields, IntoWrapped, Memoized, StringArena,
+    WrappedList, WrappedListBuilder, WrappedVec,
+};
+
+#[cfg(feature = "std")]
+pub use crate::{
+    oncecell_list, wrapped_assert_all, wrapped_hashmap, wrapped_registry, wrapped_tests, WrappedMap,
+};
+
+#[cfg(feature = "anyhow")]
+pub use crate::context_list;
+
+#[cfg(feature = "axum")]
+pub use crate::wrapped_routes;
+
+#[cfg(feature = "bevy")]
+pub use crate::wrapped_systems;
+
+#[cfg(feature = "bitvec")]
+pub use crate::wrapped_bits;
+
+#[cfg(feature = "clap")]
+pub use crate::{wrapped_args, wrapped_subcommands};
+
+#[cfg(feature = "criterion")]
+pub use crate::wrapped_benches;
+
+#[cfg(feature = "dashmap")]
+pub use crate::wrapped_dashmap;
+
+#[cfg(feature = "egui")]
+pub use crate::{wrapped_widgets, BoxedWidget};
+
+#[cfg(feature = "futures")]
+pub use crate::wrapped_unordered;
+
+#[cfg(feature = "generic-array")]
+pub use crate::wrapped_generic_array;
+
+#[cfg(feature = "iced")]
+pub use crate::wrapped_elements;
+
+#[cfg(feature = "linkme")]
+pub use crate::{wrapped_collect, wrapped_distributed_slice, wrapped_submit};
+
+#[cfg(feature = "nom")]
+pub use crate::wrapped_alt;
+
+#[cfg(feature = "phf")]
+pub use crate::wrapped_phf_map;
+
+#[cfg(feature = "priority-queue")]
+pub use crate::wrapped_priority_queue;
+
+#[cfg(feature = "proptest")]
+pub use crate::wrapped_strategy;
+
+#[cfg(feature = "pyo3")]
+pub use crate::wrapped_pylist;
+
+#[cfg(feature = "quote")]
+pub use crate::wrapped_tokens;
+
+#[cfg(feature = "rayon")]
+pub use crate::wrapped_par_vec;
+
+#[cfg(feature = "regex")]
+pub use crate::regex_list;
+
+#[cfg(feature = "ringbuffer")]
+pub use crate::wrapped_ring_buffer;
+
+#[cfg(feature = "serde_json")]
+pub use crate::json_list;
+
+#[cfg(feature = "slab")]
+pub use crate::wrapped_slab;
+
+#[cfg(feature = "slotmap")]
+pub use crate::wrapped_slotmap;
+
+#[cfg(feature = "sqlx")]
+pub use crate::wrapped_binds;
+
+#[cfg(feature = "strum")]
+pub use crate::wrapped_all_variants;
+
+#[cfg(feature = "time")]
+pub use crate::date_list;
+
+#[cfg(feature = "tokio")]
+pub use crate::spawned_list;
+
+#[cfg(feature = "tower")]
+pub use crate::{wrapped_layers, wrapped_services};
+
+#[cfg(feature = "vec1")]
+pub use crate::wrapped_vec1;
+
+#[cfg(feature = "wasm-bindgen")]
+pub use crate::wrapped_js_array;
+
+#[cfg(feature = "proc-macro")]
+pub use crate::{wrap_args, wrapped, wrapped_array};
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::boxed::Box;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Wrapper(i32);
+
+    #[test]
+    fn prelude() {
+        assert_eq!(wrapped_list![Wrapper; 1], [Wrapper(1)]);
+        assert_eq!(wrapped_tuple!(Wrapper; 1), (Wrapper(1),));
+        assert_eq!(unwrapped_list![Wrapper; Wrapper(1)], [1]);
+        assert_eq!(wrap_array([1], Box::new).unwrap_all_with(|b| *b), [1]);
+        assert_eq!(<Option<_> as Wrap<_>>::wrap(1), Some(1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn prelude_std() {
+        let list: WrappedList<Box<i32>> = wrapped_vec![#newtype Box::new; 1];
+        assert_eq!(list.iter_inner().sum::<i32>(), 1);
+        let map: WrappedMap<_, _> = wrapped_hashmap![#newtype Some; "a" => 1];
+        assert_eq!(map["a"], Some(1));
+    }
+}
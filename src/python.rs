@@ -0,0 +1,55 @@
+/// Wraps every element and collects the results into a Python `list`, each
+/// converted with `IntoPyObject`.
+///
+/// The first argument is the `Python<'py>` token, and the result is a
+/// `PyResult<Bound<'py, PyList>>` since the conversions may fail.
+///
+/// Requires the `pyo3` feature.
+///
+/// ```no_run
+/// use pyo3::prelude::*;
+/// use wrapped_list::wrapped_pylist;
+///
+/// fn celsius_to_fahrenheit(celsius: f64) -> f64 {
+///     celsius * 9.0 / 5.0 + 32.0
+/// }
+///
+/// Python::attach(|py| -> PyResult<()> {
+///     let temperatures = wrapped_pylist![py, celsius_to_fahrenheit; 0.0, 100.0]?;
+///     assert_eq!(temperatures.len(), 2);
+///     Ok(())
+/// })
+/// .unwrap();
+/// ```
+#[cfg(feature = "pyo3")]
+#[macro_export]
+macro_rules! wrapped_pylist {
+    [$py:expr, $($rest:tt)*] => {
+        $crate::__private::pyo3::types::PyList::new($py, $crate::wrapped_list![$($rest)*])
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "pyo3"))]
+mod tests {
+    use pyo3::prelude::*;
+    use pyo3::types::PyList;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[test]
+    fn pylist() {
+        Python::initialize();
+        Python::attach(|py| {
+            let list = wrapped_pylist![py, String::from; "a", "b"].unwrap();
+            assert_eq!(list.extract::<Vec<String>>().unwrap(), ["a", "b"]);
+
+            let list = wrapped_pylist![py, Some => 1, 2].unwrap();
+            assert_eq!(list.len(), 2);
+            assert_eq!(list.get_item(0).unwrap().extract::<i32>().unwrap(), 1);
+
+            let list: Bound<'_, PyList> = wrapped_pylist![py, Some: Option<i32>;].unwrap();
+            assert!(list.is_empty());
+        });
+    }
+}
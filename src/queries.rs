@@ -0,0 +1,113 @@
+/// Binds a list of parameters to a sqlx query, chaining one `.bind(...)` call
+/// per element onto the query given with `#on(query)`, in element order.
+///
+/// Every element is wrapped as with [wrapped_list](crate::wrapped_list)
+/// before it is bound, which is where conversions such as `Some`,
+/// `String::from`, or `sqlx::types::Json` go. Any query with a `bind` method
+/// works, including those returned by `query_as` and `query_scalar`.
+///
+/// Requires the `sqlx` feature.
+///
+/// ```
+/// use sqlx::{Arguments, Execute, Postgres};
+/// use wrapped_list::wrapped_binds;
+///
+/// let mut query = wrapped_binds![
+///     #on(sqlx::query::<Postgres>("SELECT * FROM users WHERE id IN ($1, $2, $3)"))
+///     i64::from; 1, 2, 3
+/// ];
+///
+/// assert_eq!(query.take_arguments().unwrap().map(|args| args.len()), Some(3));
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_binds;
+///
+/// let query = wrapped_binds![i64::from; 1, 2, 3];
+/// ```
+#[cfg(feature = "sqlx")]
+#[macro_export]
+macro_rules! wrapped_binds {
+    [#on($query:expr) $($rest:tt)*] => {
+        $crate::__sqlx![($query) [] $($rest)*]
+    };
+    [$($rest:tt)*] => {
+        ::core::compile_error!("expected `#on(query)` followed by the wrapper and the parameters")
+    };
+}
+
+/// Collects the wrapper tokens of [wrapped_binds](crate::wrapped_binds), then
+/// emits the chained `bind` calls.
+#[cfg(feature = "sqlx")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __sqlx {
+    [@element [$($wrapper:tt)*] $e:expr] => {{
+        let [wrapped] = $crate::wrapped_list![$($wrapper)* ; $e];
+        wrapped
+    }};
+    [($query:expr) $wrapper:tt ; $($e:expr),* $(,)?] => {
+        $query $(.bind($crate::__sqlx![@element $wrapper $e]))*
+    };
+    [$query:tt [$($wrapper:tt)*] => $($rest:tt)*] => {
+        $crate::__sqlx![$query [$($wrapper)*] ; $($rest)*]
+    };
+    [$query:tt [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by the elements")
+    };
+    [$query:tt [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::__sqlx![$query [$($wrapper)* $next] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "sqlx"))]
+mod tests {
+    use sqlx::postgres::PgArguments;
+    use sqlx::query::Query;
+    use sqlx::{Arguments, Execute, Postgres};
+    use std::string::String;
+
+    struct Param;
+
+    impl Param {
+        fn text(value: &str) -> String {
+            value.into()
+        }
+    }
+
+    macro_rules! nullable {
+        ($e:expr) => {
+            Some($e)
+        };
+    }
+
+    fn bound(mut query: Query<'_, Postgres, PgArguments>) -> usize {
+        query.take_arguments().unwrap().map_or(0, |args| args.len())
+    }
+
+    #[test]
+    fn binds() {
+        let sql = "SELECT $1, $2";
+        assert_eq!(
+            bound(wrapped_binds![#on(sqlx::query(sql)) <Param>::text; "a", "b"]),
+            2
+        );
+
+        let limit = 10;
+        let query = wrapped_binds![#on(sqlx::query(sql)) nullable! => limit, 20];
+        assert_eq!(bound(query), 2);
+
+        let double = |n: i32| n * 2;
+        let query = wrapped_binds![#on(sqlx::query(sql)) double; 1];
+        assert_eq!(bound(query), 1);
+
+        assert_eq!(
+            bound(wrapped_binds![#on(sqlx::query(sql)) String::from;]),
+            0
+        );
+
+        let query = wrapped_binds![#on(sqlx::query_scalar::<Postgres, i64>(sql)) i64::from; 1, 2];
+        assert_eq!(query.sql(), sql);
+    }
+}
@@ -0,0 +1,217 @@
+/// Expands a range of literals into an array at compile time and wraps every
+/// value with the wrapper, using the same wrapper forms as
+/// [wrapped_list](crate::wrapped_list).
+///
+/// Used by the `#range` modifier.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __range {
+    [$kind:ident $wrapper:tt $start:literal .. $end:literal] => {
+        $crate::__range![@values $kind $wrapper $start, $end, 1, false]
+    };
+    [$kind:ident $wrapper:tt $start:literal ..= $end:literal] => {
+        $crate::__range![@values $kind $wrapper $start, $end, 1, true]
+    };
+    [$kind:ident $wrapper:tt ($start:literal .. $end:literal).step_by($step:literal)] => {
+        $crate::__range![@values $kind $wrapper $start, $end, $step, false]
+    };
+    [$kind:ident $wrapper:tt ($start:literal ..= $end:literal).step_by($step:literal)] => {
+        $crate::__range![@values $kind $wrapper $start, $end, $step, true]
+    };
+    [@values $kind:ident $wrapper:tt $start:literal, $end:literal, $step:literal, $inclusive:literal] => {
+        $crate::__range![@map $kind $wrapper {
+            const CHARS: bool = $crate::__private::is_char_range(&$start);
+            const LEN: usize = $crate::__private::range_len(
+                $start as i128,
+                $end as i128,
+                $step as i128,
+                $inclusive,
+                CHARS,
+            );
+            const VALUES: [i128; LEN] =
+                $crate::__private::range_values($start as i128, $step as i128, CHARS);
+            VALUES.map(|value| $crate::__private::RangeValue::with_value($start, value))
+        }]
+    };
+    [@map list [$($wrapper:tt)*] $values:tt] => {
+        $values.map(|element| {
+            let [wrapped] = $crate::wrapped_list![$($wrapper)* ; element];
+            wrapped
+        })
+    };
+    [@map vec $wrapper:tt $values:tt] => {
+        $crate::__private::Vec::from($crate::__range![@map list $wrapper $values])
+    };
+    [$kind:ident [$($wrapper:tt)*] ; $($range:tt)*] => {
+        $crate::__range![$kind [$($wrapper)*] $($range)*]
+    };
+    [$kind:ident [$($wrapper:tt)*] => $($range:tt)*] => {
+        $crate::__range![$kind [$($wrapper)*] $($range)*]
+    };
+    [$kind:ident [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by a range of literals")
+    };
+    [$kind:ident [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::__range![$kind [$($wrapper)* $next] $($rest)*]
+    };
+}
+
+/// Returns the number of values in a range of integer or `char` literals,
+/// failing the build for a step that isn't positive.
+///
+/// Like `RangeInclusive<char>`, `char` ranges skip the surrogate code points,
+/// which aren't valid `char`s.
+#[doc(hidden)]
+pub const fn range_len(start: i128, end: i128, step: i128, inclusive: bool, chars: bool) -> usize {
+    assert!(step > 0, "the step of a range must be positive");
+    let (start, end) = if chars {
+        (char_index(start), char_index(end))
+    } else {
+        (start, end)
+    };
+    let end = if inclusive { end + 1 } else { end };
+    if start >= end {
+        0
+    } else {
+        ((end - start + step - 1) / step) as usize
+    }
+}
+
+/// Returns the values of a range with `N` values as `i128`s, skipping the
+/// surrogate code points if the bounds are `char`s.
+#[doc(hidden)]
+pub const fn range_values<const N: usize>(start: i128, step: i128, chars: bool) -> [i128; N] {
+    let start = if chars { char_index(start) } else { start };
+    let mut values = [start; N];
+    let mut index = 1;
+    while index < N {
+        values[index] = values[index - 1] + step;
+        index += 1;
+    }
+    if chars {
+        let mut index = 0;
+        while index < N {
+            values[index] = char_value(values[index]);
+            index += 1;
+        }
+    }
+    values
+}
+
+/// Returns whether the bounds of a range are `char`s.
+#[doc(hidden)]
+pub const fn is_char_range<T: RangeValue>(_: &T) -> bool {
+    T::CHAR
+}
+
+/// The first surrogate code point and the number of surrogates.
+const SURROGATES: (i128, i128) = (0xD800, 0x800);
+
+/// Maps a `char` to its position among all valid `char`s.
+const fn char_index(value: i128) -> i128 {
+    if value >= SURROGATES.0 + SURROGATES.1 {
+        value - SURROGATES.1
+    } else {
+        value
+    }
+}
+
+/// Maps a position among all valid `char`s back to the `char` value.
+const fn char_value(index: i128) -> i128 {
+    if index >= SURROGATES.0 {
+        index + SURROGATES.1
+    } else {
+        index
+    }
+}
+
+/// Types that can be the bounds of a `#range`.
+#[doc(hidden)]
+pub trait RangeValue: Copy {
+    /// Whether the type is `char`, whose ranges skip the surrogate code points.
+    const CHAR: bool = false;
+
+    /// Converts `value`, a value of a range starting at `self`, back to the
+    /// type of the bounds.
+    fn with_value(self, value: i128) -> Self;
+}
+
+macro_rules! range_value {
+    ($($t:ty),*) => {
+        $(
+            impl RangeValue for $t {
+                fn with_value(self, value: i128) -> Self {
+                    value as $t
+                }
+            }
+        )*
+    };
+}
+
+range_value!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl RangeValue for char {
+    const CHAR: bool = true;
+
+    fn with_value(self, value: i128) -> Self {
+        char::from_u32(value as u32).expect("`char` ranges skip the surrogate code points")
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::range_len;
+
+    #[derive(Debug, PartialEq)]
+    struct Wrapper(u8);
+
+    #[test]
+    fn len() {
+        assert_eq!(range_len(0, 100, 10, false, false), 10);
+        assert_eq!(range_len(0, 100, 10, true, false), 11);
+        assert_eq!(range_len(-3, 3, 4, false, false), 2);
+        assert_eq!(range_len(5, 5, 1, false, false), 0);
+        assert_eq!(range_len(5, 5, 1, true, false), 1);
+        assert_eq!(range_len(9, 1, 1, true, false), 0);
+        assert_eq!(range_len(0xD7FF, 0xE000, 1, true, true), 2);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_eq!(
+            wrapped_list![#range Wrapper; 1..4],
+            [Wrapper(1), Wrapper(2), Wrapper(3)]
+        );
+        assert_eq!(
+            wrapped_list![#range Wrapper => (0..=255).step_by(85)],
+            [Wrapper(0), Wrapper(85), Wrapper(170), Wrapper(255)]
+        );
+        assert_eq!(wrapped_list![#range i64::from; -2i32..=-1], [-2, -1]);
+        assert_eq!(wrapped_list![#range Some; (0..10).step_by(4)].len(), 3);
+        let empty: [Wrapper; 0] = wrapped_list![#range Wrapper; 3..3];
+        assert!(empty.is_empty());
+        #[cfg(feature = "alloc")]
+        assert_eq!(wrapped_vec![#range Wrapper; 7..=7], [Wrapper(7)]);
+    }
+
+    #[test]
+    fn chars() {
+        let letters = wrapped_list![#range Some; 'a'..='z'];
+        assert_eq!(letters.len(), 26);
+        assert_eq!(letters[25], Some('z'));
+        assert_eq!(
+            wrapped_list![#range u32::from; ('a'..'g').step_by(2)],
+            [97, 99, 101]
+        );
+        assert_eq!(wrapped_list![#range Some; 'é'..='é'], [Some('é')]);
+        assert_eq!(
+            wrapped_list![#range Some; '\u{D7FF}'..='\u{E000}'],
+            [Some('\u{D7FF}'), Some('\u{E000}')]
+        );
+        assert_eq!(
+            wrapped_list![#range Some; ('\u{D7FE}'..'\u{E002}').step_by(2)],
+            [Some('\u{D7FE}'), Some('\u{E000}')]
+        );
+    }
+}
@@ -0,0 +1,36 @@
+/// Reverses the elements of a list before passing it back to
+/// [wrapped_list](crate::wrapped_list) or [wrapped_vec](crate::wrapped_vec).
+///
+/// Used by the `#rev` modifier. The elements are reversed eight at a time to
+/// keep the recursion depth down for long lists.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rev {
+    [$kind:ident [$($wrapper:tt)*] ; $($e:expr),* $(,)?] => {
+        $crate::__rev![@reverse $kind [$($wrapper)*] [] $($e,)*]
+    };
+    [$kind:ident [$($wrapper:tt)*] => $($e:expr),* $(,)?] => {
+        $crate::__rev![@reverse $kind [$($wrapper)*] [] $($e,)*]
+    };
+    [@reverse $kind:ident $wrapper:tt [$($done:tt)*]
+        $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr, $e8:expr,
+        $($rest:tt)*
+    ] => {
+        $crate::__rev![@reverse $kind $wrapper [$e8, $e7, $e6, $e5, $e4, $e3, $e2, $e1, $($done)*] $($rest)*]
+    };
+    [@reverse $kind:ident $wrapper:tt [$($done:tt)*] $e:expr, $($rest:tt)*] => {
+        $crate::__rev![@reverse $kind $wrapper [$e, $($done)*] $($rest)*]
+    };
+    [@reverse list [$($wrapper:tt)*] [$($done:tt)*]] => {
+        $crate::wrapped_list![$($wrapper)* ; $($done)*]
+    };
+    [@reverse vec [$($wrapper:tt)*] [$($done:tt)*]] => {
+        $crate::wrapped_vec![$($wrapper)* ; $($done)*]
+    };
+    [$kind:ident [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by the elements")
+    };
+    [$kind:ident [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::__rev![$kind [$($wrapper)* $next] $($rest)*]
+    };
+}
@@ -0,0 +1,81 @@
+#[cfg(feature = "ringbuffer")]
+use ringbuffer::{ConstGenericRingBuffer, RingBuffer};
+
+/// Functions identically to [wrapped_list](crate::wrapped_list), but pushes
+/// the wrapped elements into a bounded
+/// [`ConstGenericRingBuffer`](ringbuffer::ConstGenericRingBuffer).
+///
+/// The capacity is taken from the buffer's type, so it can be larger than the
+/// number of elements to leave room for later samples. Having more elements
+/// than the capacity fails the build.
+///
+/// Requires the `ringbuffer` feature.
+///
+/// ```
+/// use ringbuffer::{ConstGenericRingBuffer, RingBuffer};
+/// use wrapped_list::wrapped_ring_buffer;
+///
+/// let mut window: ConstGenericRingBuffer<f64, 3> = wrapped_ring_buffer![f64::from; 1, 2];
+/// window.enqueue(3.0);
+/// window.enqueue(4.0);
+///
+/// assert_eq!(window.iter().sum::<f64>(), 9.0);
+/// ```
+///
+/// ```compile_fail
+/// use ringbuffer::ConstGenericRingBuffer;
+/// use wrapped_list::wrapped_ring_buffer;
+///
+/// let window: ConstGenericRingBuffer<f64, 1> = wrapped_ring_buffer![f64::from; 1, 2];
+/// ```
+#[cfg(feature = "ringbuffer")]
+#[macro_export]
+macro_rules! wrapped_ring_buffer {
+    [$($rest:tt)*] => {
+        $crate::__private::ring_buffer($crate::wrapped_list![$($rest)*])
+    };
+}
+
+/// Pushes `values` into a new ring buffer, failing the build if they don't fit.
+#[cfg(feature = "ringbuffer")]
+#[doc(hidden)]
+pub fn ring_buffer<T, const N: usize, const CAP: usize>(
+    values: [T; N],
+) -> ConstGenericRingBuffer<T, CAP> {
+    const { assert!(N <= CAP, "more elements than the ring buffer capacity") };
+    let mut buffer = ConstGenericRingBuffer::new();
+    for value in values {
+        buffer.enqueue(value);
+    }
+    buffer
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "ringbuffer"))]
+mod tests {
+    use ringbuffer::{ConstGenericRingBuffer, RingBuffer};
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn ring_buffer() {
+        let mut buffer: ConstGenericRingBuffer<Wrapper, 4> = wrapped_ring_buffer![Wrapper; 1, 2, 3];
+        assert_eq!(buffer.len(), 3);
+        buffer.enqueue(Wrapper(4));
+        assert!(buffer.is_full());
+        assert_eq!(buffer.enqueue(Wrapper(5)), Some(Wrapper(1)));
+        assert_eq!(buffer.front(), Some(&Wrapper(2)));
+
+        let buffer: ConstGenericRingBuffer<_, 2> = wrapped_ring_buffer![wrapper_macro! => 1, 2];
+        assert_eq!(buffer.back(), Some(&Wrapper(2)));
+        let buffer: ConstGenericRingBuffer<Wrapper, 1> = wrapped_ring_buffer![Wrapper;];
+        assert!(buffer.is_empty());
+    }
+}
@@ -0,0 +1,113 @@
+/// Builds an axum [`Router`](axum::Router) with one route per `path => handler`
+/// pair, every handler wrapped with the same method router function, such as
+/// `get` or `post`.
+///
+/// Literal paths are checked for duplicates at compile time, since axum would
+/// otherwise panic on the overlapping route when the router is built.
+///
+/// Requires the `axum` feature.
+///
+/// ```
+/// use axum::{routing::get, Router};
+/// use wrapped_list::wrapped_routes;
+///
+/// async fn health() -> &'static str {
+///     "ok"
+/// }
+///
+/// async fn metrics() -> String {
+///     String::from("requests 0")
+/// }
+///
+/// let router: Router = wrapped_routes![get; "/health" => health, "/metrics" => metrics];
+/// ```
+///
+/// ```compile_fail
+/// use axum::{routing::get, Router};
+/// use wrapped_list::wrapped_routes;
+///
+/// async fn health() {}
+///
+/// let router: Router = wrapped_routes![get; "/health" => health, "/health" => health];
+/// ```
+#[cfg(feature = "axum")]
+#[macro_export]
+macro_rules! wrapped_routes {
+    [@wrap [] $wrapper:tt $($k:literal => $v:expr),* $(,)?] => {{
+        $crate::__unique_keys!($($k),*);
+        $crate::__private::axum::Router::new() $(.route($k, $crate::__apply_wrapper![$wrapper $v]))*
+    }};
+    [@wrap [] $wrapper:tt $($k:expr => $v:expr),* $(,)?] => {
+        $crate::__private::axum::Router::new() $(.route($k, $crate::__apply_wrapper![$wrapper $v]))*
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![map wrapped_routes [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "axum"))]
+mod tests {
+    use axum::body::Body;
+    use axum::handler::Handler;
+    use axum::http::{Request, StatusCode};
+    use axum::routing::{get, post, MethodRouter};
+    use axum::Router;
+    use tower::ServiceExt;
+
+    async fn health() -> &'static str {
+        "ok"
+    }
+
+    async fn created() -> StatusCode {
+        StatusCode::CREATED
+    }
+
+    struct Api;
+
+    impl Api {
+        fn get<H: Handler<T, ()>, T: 'static>(handler: H) -> MethodRouter {
+            get(handler)
+        }
+    }
+
+    macro_rules! get_route {
+        ($e:expr) => {
+            get($e)
+        };
+    }
+
+    fn status(router: Router, method: &str, uri: &str) -> StatusCode {
+        let request = Request::builder()
+            .method(method)
+            .uri(uri)
+            .body(Body::empty());
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        runtime
+            .block_on(router.oneshot(request.unwrap()))
+            .unwrap()
+            .status()
+    }
+
+    #[test]
+    fn routes() {
+        let router: Router = wrapped_routes![get; "/health" => health, "/ready" => health];
+        assert_eq!(status(router.clone(), "GET", "/ready"), StatusCode::OK);
+        assert_eq!(
+            status(router, "POST", "/health"),
+            StatusCode::METHOD_NOT_ALLOWED
+        );
+
+        let path = "/items";
+        let router: Router = wrapped_routes![post; path => created];
+        assert_eq!(status(router, "POST", "/items"), StatusCode::CREATED);
+
+        let router: Router = wrapped_routes![get_route!; "/health" => health];
+        assert_eq!(status(router, "GET", "/health"), StatusCode::OK);
+
+        let router: Router = wrapped_routes![<Api>::get; "/" => health];
+        assert_eq!(status(router, "GET", "/"), StatusCode::OK);
+    }
+}
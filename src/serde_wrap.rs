@@ -0,0 +1,117 @@
+//! Helpers for `#[serde(with = "wrapped_list::serde_wrap")]`, which deserialize
+//! the inner value of a field and wrap it with the field type's [`Wrap`]
+//! implementation.
+//!
+//! The inner type is inferred from the `Wrap` implementation, so the wrapper must
+//! implement `Wrap` for exactly one type. Serializing delegates to the wrapper's
+//! own `Serialize` implementation. The [`vec`] module does the same for every
+//! element of a `Vec` field.
+//!
+//! Requires the `serde` feature.
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use wrapped_list::Wrap;
+//!
+//! #[derive(Debug, PartialEq, Serialize)]
+//! struct Celsius(f64);
+//!
+//! impl Wrap<f64> for Celsius {
+//!     type Output = Self;
+//!
+//!     fn wrap(value: f64) -> Self {
+//!         Celsius(value)
+//!     }
+//! }
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Reading {
+//!     #[serde(with = "wrapped_list::serde_wrap")]
+//!     temperature: Celsius,
+//!     #[serde(with = "wrapped_list::serde_wrap::vec")]
+//!     history: Vec<Celsius>,
+//! }
+//!
+//! let reading: Reading = serde_json::from_str(r#"{"temperature":21.5,"history":[20.0]}"#).unwrap();
+//!
+//! assert_eq!(reading.temperature, Celsius(21.5));
+//! assert_eq!(reading.history, [Celsius(20.0)]);
+//! ```
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Wrap;
+
+/// Serializes the wrapper with its own `Serialize` implementation.
+pub fn serialize<W, S>(wrapped: &W, serializer: S) -> Result<S::Ok, S::Error>
+where
+    W: Serialize,
+    S: Serializer,
+{
+    wrapped.serialize(serializer)
+}
+
+/// Deserializes the inner value and wraps it.
+pub fn deserialize<'de, T, W, D>(deserializer: D) -> Result<W, D::Error>
+where
+    T: Deserialize<'de>,
+    W: Wrap<T, Output = W>,
+    D: Deserializer<'de>,
+{
+    T::deserialize(deserializer).map(W::wrap)
+}
+
+/// The same helpers for `Vec` fields, wrapping every element.
+pub mod vec {
+    use alloc::vec::Vec;
+
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{UnwrapAll, Wrap};
+
+    /// Serializes the elements with their own `Serialize` implementation.
+    pub fn serialize<W, S>(wrapped: &[W], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        W: Serialize,
+        S: Serializer,
+    {
+        wrapped.serialize(serializer)
+    }
+
+    /// Deserializes a sequence of inner values and wraps each of them.
+    pub fn deserialize<'de, T, W, D>(deserializer: D) -> Result<Vec<W>, D::Error>
+    where
+        T: Deserialize<'de>,
+        W: Wrap<T, Output = W>,
+        D: Deserializer<'de>,
+    {
+        Vec::<T>::deserialize(deserializer).map(|values| values.unwrap_all_with(W::wrap))
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use std::cell::Cell;
+    use std::num::Wrapping;
+    use std::string::String;
+    use std::vec::Vec;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde_wrap")]
+        limit: Cell<u32>,
+        #[serde(with = "crate::serde_wrap::vec")]
+        paths: Vec<Wrapping<String>>,
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"limit":3,"paths":["a","b"]}"#;
+        let config: Config = serde_json::from_str(json).unwrap();
+        assert_eq!(config.limit.get(), 3);
+        assert_eq!(config.paths[1].0, "b");
+        assert_eq!(serde_json::to_string(&config).unwrap(), json);
+    }
+}
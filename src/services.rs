@@ -0,0 +1,195 @@
+/// Boxes every element into a tower [`BoxService`](tower::util::BoxService),
+/// so services of different types can be collected into a single array, such
+/// as the backends of a router or balancer.
+///
+/// Put a wrapper before a `;` to apply it to every element first, such as a
+/// function that turns a handler into a service.
+///
+/// Requires the `tower` feature.
+///
+/// ```
+/// use std::convert::Infallible;
+/// use tower::util::BoxService;
+/// use tower::{service_fn, ServiceExt};
+/// use wrapped_list::wrapped_services;
+///
+/// async fn double(x: u32) -> Result<u32, Infallible> {
+///     Ok(x * 2)
+/// }
+///
+/// async fn square(x: u32) -> Result<u32, Infallible> {
+///     Ok(x * x)
+/// }
+///
+/// let services: [BoxService<u32, u32, Infallible>; 2] = wrapped_services![service_fn; double, square];
+///
+/// let [double, square] = services;
+/// # futures::executor::block_on(async {
+/// assert_eq!(double.oneshot(3).await, Ok(6));
+/// assert_eq!(square.oneshot(3).await, Ok(9));
+/// # });
+/// ```
+#[cfg(feature = "tower")]
+#[macro_export]
+macro_rules! wrapped_services {
+    [$($rest:tt)*] => {
+        $crate::__tower![$crate::__private::tower::util::BoxService::new, $($rest)*]
+    };
+}
+
+/// Boxes every element into a tower [`BoxLayer`](tower::util::BoxLayer),
+/// the layer counterpart of [wrapped_services](crate::wrapped_services).
+///
+/// Prefix the list with `#on(builder)` to fold the layers onto a
+/// [`ServiceBuilder`](tower::ServiceBuilder) with one `.layer(...)` call per
+/// element instead, which keeps every layer's concrete type. The first layer
+/// is the outermost, as with chained `.layer` calls.
+///
+/// Requires the `tower` feature.
+///
+/// ```
+/// use std::convert::Infallible;
+/// use tower::util::MapRequestLayer;
+/// use tower::{service_fn, ServiceBuilder, ServiceExt};
+/// use wrapped_list::wrapped_layers;
+///
+/// let add_one = MapRequestLayer::new(|x: u32| x + 1);
+/// let double = MapRequestLayer::new(|x: u32| x * 2);
+///
+/// let service = wrapped_layers![#on(ServiceBuilder::new()) add_one, double]
+///     .service(service_fn(|x: u32| async move { Ok::<_, Infallible>(x) }));
+///
+/// # futures::executor::block_on(async {
+/// assert_eq!(service.oneshot(5).await, Ok(12));
+/// # });
+/// ```
+#[cfg(feature = "tower")]
+#[macro_export]
+macro_rules! wrapped_layers {
+    [#on($builder:expr) $($wrapper:ident)::+ ! ; $($rest:tt)*] => {
+        $crate::__tower![@chain ($builder) [$($wrapper)::+ !] $($rest)*]
+    };
+    [#on($builder:expr) $($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::__tower![@chain ($builder) [$($wrapper)::+ !] $($rest)*]
+    };
+    [#on($builder:expr) $($e:expr),* $(,)?] => {
+        $builder $(.layer($e))*
+    };
+    [#on($builder:expr) $($rest:tt)*] => {
+        $crate::__tower![@builder ($builder) [] $($rest)*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__tower![$crate::__private::tower::util::BoxLayer::new, $($rest)*]
+    };
+}
+
+/// Implements [wrapped_services](crate::wrapped_services) and
+/// [wrapped_layers](crate::wrapped_layers), given the function that boxes a
+/// single element, or the builder that `#on` folds the layers onto.
+#[cfg(feature = "tower")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tower {
+    [@builder $builder:tt [$($wrapper:tt)*] ; $($rest:tt)*] => {
+        $crate::__tower![@chain $builder [$($wrapper)*] $($rest)*]
+    };
+    [@builder $builder:tt [$($wrapper:tt)*] => $($rest:tt)*] => {
+        $crate::__tower![@chain $builder [$($wrapper)*] $($rest)*]
+    };
+    [@builder $builder:tt [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by the elements")
+    };
+    [@builder $builder:tt [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::__tower![@builder $builder [$($wrapper)* $next] $($rest)*]
+    };
+    [@chain ($builder:expr) $wrapper:tt $($e:expr),* $(,)?] => {
+        $builder $(.layer($crate::__tower![@element $wrapper $e]))*
+    };
+    [@element [$($wrapper:tt)*] $e:expr] => {{
+        let [wrapped] = $crate::wrapped_list![$($wrapper)* ; $e];
+        wrapped
+    }};
+    [@wrap [$boxed:path] $wrapper:tt $($e:expr),* $(,)?] => {
+        [$($boxed($crate::__apply_wrapper![$wrapper $e])),*]
+    };
+    [$boxed:path, $($wrapper:ident)::+ ! ; $($rest:tt)*] => {
+        $crate::__parse_wrapper![list __tower [$boxed] $($wrapper)::+ ! ; $($rest)*]
+    };
+    [$boxed:path, $($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::__parse_wrapper![list __tower [$boxed] $($wrapper)::+ ! => $($rest)*]
+    };
+    [$boxed:path, $($e:expr),* $(,)?] => {
+        [$($boxed($e)),*]
+    };
+    [$boxed:path, $($rest:tt)*] => {
+        $crate::__parse_wrapper![list __tower [$boxed] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "tower"))]
+mod tests {
+    use core::convert::Infallible;
+    use futures::executor::block_on;
+    use std::vec::Vec;
+    use tower::util::BoxLayer;
+    use tower::util::{BoxService, MapRequestLayer, MapResponseLayer};
+    use tower::{service_fn, ServiceBuilder, ServiceExt};
+
+    type Boxed = BoxService<u32, u32, Infallible>;
+
+    fn constant(value: u32) -> Boxed {
+        BoxService::new(service_fn(move |_: u32| async move { Ok(value) }))
+    }
+
+    fn echo() -> Boxed {
+        BoxService::new(service_fn(|x: u32| async move { Ok(x) }))
+    }
+
+    struct Layers;
+
+    impl Layers {
+        fn add(n: u32) -> MapRequestLayer<impl Fn(u32) -> u32 + Clone> {
+            MapRequestLayer::new(move |x: u32| x + n)
+        }
+    }
+
+    macro_rules! add {
+        ($e:expr) => {
+            Layers::add($e)
+        };
+    }
+
+    fn call(service: Boxed, request: u32) -> u32 {
+        block_on(service.oneshot(request)).unwrap()
+    }
+
+    #[test]
+    fn services() {
+        let double = service_fn(|x: u32| async move { Ok::<_, Infallible>(x * 2) });
+        let services: [Boxed; 2] = wrapped_services![double, constant(7)];
+        let results: Vec<u32> = services.into_iter().map(|s| call(s, 3)).collect();
+        assert_eq!(results, [6, 7]);
+        let [one, two]: [Boxed; 2] = wrapped_services![constant => 1, 2];
+        assert_eq!((call(one, 0), call(two, 0)), (1, 2));
+    }
+
+    #[test]
+    fn layers() {
+        let layers: [BoxLayer<Boxed, u32, u32, Infallible>; 2] =
+            wrapped_layers![Layers::add(1), MapResponseLayer::new(|x: u32| x * 10),];
+        let [add, times_ten] = layers;
+        let service = ServiceBuilder::new()
+            .layer(add)
+            .layer(times_ten)
+            .service(echo());
+        assert_eq!(block_on(service.oneshot(4)), Ok(50));
+
+        let service = wrapped_layers![#on(ServiceBuilder::new()) <Layers>::add; 1, 2]
+            .service(service_fn(|x: u32| async move { Ok::<_, Infallible>(x) }));
+        assert_eq!(block_on(service.oneshot(0)), Ok(3));
+        let service = wrapped_layers![#on(ServiceBuilder::new()) add! => 5]
+            .service(service_fn(|x: u32| async move { Ok::<_, Infallible>(x) }));
+        assert_eq!(block_on(service.oneshot(0)), Ok(5));
+    }
+}
@@ -0,0 +1,51 @@
+/// Functions identically to [wrapped_list](crate::wrapped_list), but returns a
+/// [`GenericArray`](generic_array::GenericArray) whose `typenum` length is
+/// derived from the number of elements.
+///
+/// The conversion is `const`, so the macro can also initialize `const` and
+/// `static` items when the wrapper is a `const fn`.
+///
+/// Requires the `generic-array` feature.
+///
+/// ```
+/// use generic_array::{typenum::U3, GenericArray};
+/// use wrapped_list::wrapped_generic_array;
+///
+/// let key: GenericArray<u16, U3> = wrapped_generic_array![u16::from; 1u8, 2u8, 3u8];
+///
+/// assert_eq!(key.as_slice(), [1, 2, 3]);
+/// ```
+#[cfg(feature = "generic-array")]
+#[macro_export]
+macro_rules! wrapped_generic_array {
+    [$($rest:tt)*] => {
+        $crate::__private::generic_array::GenericArray::from_array($crate::wrapped_list![$($rest)*])
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "generic-array"))]
+mod tests {
+    use generic_array::typenum::{U0, U2};
+    use generic_array::GenericArray;
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(i32);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    const ARRAY: GenericArray<Wrapper, U2> = wrapped_generic_array![Wrapper; 1, 2];
+
+    #[test]
+    fn generic_array() {
+        assert_eq!(ARRAY.as_slice(), [Wrapper(1), Wrapper(2)]);
+        let array: GenericArray<_, U2> = wrapped_generic_array![wrapper_macro! => 3, 4];
+        assert_eq!(array[1], Wrapper(4));
+        let empty: GenericArray<Wrapper, U0> = wrapped_generic_array![Wrapper;];
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+/// Builds a proptest [`Strategy`](proptest::strategy::Strategy) that generates
+/// vectors of wrapped values, so property tests construct their inputs the same
+/// way production code does.
+///
+/// The wrapper is followed by the strategy for the raw values and the number of
+/// elements, which is anything `proptest::collection::vec` accepts, such as
+/// `8` or `1..=8`. The wrapper is called with every generated value, so its
+/// output must implement `Debug`.
+///
+/// Requires the `proptest` feature.
+///
+/// ```
+/// use proptest::strategy::{Strategy, ValueTree};
+/// use proptest::test_runner::TestRunner;
+/// use wrapped_list::wrapped_strategy;
+///
+/// #[derive(Debug)]
+/// struct Percent(i32);
+///
+/// let strategy = wrapped_strategy![Percent; 0..=100i32; 1..=8];
+/// let values = strategy.new_tree(&mut TestRunner::default()).unwrap().current();
+///
+/// assert!((1..=8).contains(&values.len()));
+/// assert!(values.iter().all(|percent| (0..=100).contains(&percent.0)));
+/// ```
+#[cfg(feature = "proptest")]
+#[macro_export]
+macro_rules! wrapped_strategy {
+    [@wrap [] $wrapper:tt $strategy:expr ; $size:expr $(,)?] => {
+        $crate::__private::proptest::collection::vec(
+            $crate::__private::proptest::strategy::Strategy::prop_map(
+                $strategy,
+                $crate::__apply_wrapper![@fn $wrapper],
+            ),
+            $size,
+        )
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_strategy [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "proptest"))]
+mod tests {
+    use proptest::prelude::*;
+    use std::boxed::Box;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Id(u8);
+
+    impl Id {
+        fn new(value: u8) -> Self {
+            Id(value)
+        }
+    }
+
+    macro_rules! id {
+        ($e:expr) => {
+            Id($e)
+        };
+    }
+
+    proptest! {
+        #[test]
+        fn wrapped_strategy(
+            ids in wrapped_strategy![Id; 1..10u8; 1..=4],
+            exact in wrapped_strategy![id! => any::<u8>(); 3],
+            boxed in wrapped_strategy![derive Box<_>; Just(7); 2],
+            qualified in wrapped_strategy![<Id>::new; 0..2u8; 0..2],
+        ) {
+            prop_assert!((1..=4).contains(&ids.len()));
+            prop_assert!(ids.iter().all(|id| (1..10).contains(&id.0)));
+            prop_assert_eq!(exact.len(), 3);
+            prop_assert_eq!(boxed, [Box::new(7), Box::new(7)]);
+            prop_assert!(qualified.len() < 2 && qualified.iter().all(|id| id.0 < 2));
+        }
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::ops::{Index, Range};
+
+/// Creates an array of `&'static CStr`s from string literals, appending the
+/// NUL terminator to each.
+///
+/// Every literal is checked at compile time, so one with an interior NUL byte
+/// fails the build instead of being truncated at runtime.
+///
+/// ```
+/// use std::ffi::CStr;
+/// use wrapped_list::cstr_list;
+///
+/// static NAMES: [&CStr; 2] = cstr_list!["alpha", "beta"];
+///
+/// assert_eq!(NAMES[1].to_bytes_with_nul(), b"beta\0");
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::cstr_list;
+///
+/// let names = cstr_list!["al\0pha"];
+/// ```
+#[macro_export]
+macro_rules! cstr_list {
+    [$($s:literal),* $(,)?] => {
+        [$(const {
+            match ::core::ffi::CStr::from_bytes_with_nul(::core::concat!($s, "\0").as_bytes()) {
+                ::core::result::Result::Ok(s) => s,
+                ::core::result::Result::Err(_) => ::core::panic!(
+                    "{}",
+                    ::core::concat!("interior NUL byte in ", ::core::stringify!($s)),
+                ),
+            }
+        }),*]
+    };
+}
+
+/// Creates a `Vec<String>` from string slices.
+///
+/// Prefix the literals with `#arena` to get a [`StringArena`] instead, which
+/// stores every string in one buffer whose size is known at compile time, so
+/// the whole table takes a single allocation.
+///
+/// Requires the `alloc` feature.
+///
+/// ```
+/// use wrapped_list::string_list;
+///
+/// let names: Vec<String> = string_list!["ada", "grace"];
+/// let arena = string_list![#arena "ada", "grace"];
+///
+/// assert_eq!(names, ["ada", "grace"]);
+/// assert_eq!(&arena[1], "grace");
+/// assert_eq!(arena.as_str(), "adagrace");
+/// ```
+#[cfg(feature = "alloc")]
+#[macro_export]
+macro_rules! string_list {
+    [#arena $($s:literal),* $(,)?] => {
+        $crate::StringArena::new(::core::concat!($($s),*), [$(<str>::len($s)),*])
+    };
+    [$($s:expr),* $(,)?] => {
+        $crate::__private::vec![$($crate::__private::String::from($s)),*]
+    };
+}
+
+/// Table of strings stored back to back in a single `String`.
+///
+/// Created by [string_list](crate::string_list) with the `#arena` modifier.
+///
+/// Requires the `alloc` feature.
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct StringArena<const N: usize> {
+    buffer: String,
+    ends: [usize; N],
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> StringArena<N> {
+    /// Copies `contents`, the strings concatenated, into a new arena split
+    /// into strings of the given byte lengths.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths don't add up to the length of `contents` or don't
+    /// fall on character boundaries.
+    pub fn new(contents: &str, lens: [usize; N]) -> Self {
+        let mut end = 0;
+        let ends = lens.map(|len| {
+            end += len;
+            end
+        });
+        assert_eq!(end, contents.len(), "lengths don't match the contents");
+        assert!(
+            ends.iter().all(|&end| contents.is_char_boundary(end)),
+            "lengths don't fall on character boundaries"
+        );
+        StringArena {
+            buffer: String::from(contents),
+            ends,
+        }
+    }
+
+    /// Returns the string at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        self.buffer.get(self.range(index)?)
+    }
+
+    /// Returns the byte range of the string at `index` within
+    /// [`as_str`](Self::as_str).
+    pub fn range(&self, index: usize) -> Option<Range<usize>> {
+        let end = *self.ends.get(index)?;
+        let start = index
+            .checked_sub(1)
+            .map_or(0, |previous| self.ends[previous]);
+        Some(start..end)
+    }
+
+    /// Returns an iterator over the strings.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        (0..N).map(|index| &self[index])
+    }
+
+    /// Returns the number of strings.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the arena holds no strings.
+    pub fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// Returns all the strings concatenated.
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Returns the backing buffer.
+    pub fn into_string(self) -> String {
+        self.buffer
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> Index<usize> for StringArena<N> {
+    type Output = str;
+
+    fn index(&self, index: usize) -> &str {
+        match self.get(index) {
+            Some(s) => s,
+            None => panic!("index {index} out of bounds for {N} strings"),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const N: usize> fmt::Debug for StringArena<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use core::ffi::CStr;
+
+    #[test]
+    fn cstr_list() {
+        let names = cstr_list!["a", "", "ab c",];
+        assert_eq!(names, [c"a", c"", c"ab c"]);
+        assert_eq!(cstr_list!["{x}", "}"], [c"{x}", c"}"]);
+        let empty: [&CStr; 0] = cstr_list![];
+        assert!(empty.is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn string_list() {
+        use crate::StringArena;
+        use std::format;
+        use std::string::String;
+        use std::vec::Vec;
+
+        let names = string_list!["a", "bc",];
+        assert_eq!(names, [String::from("a"), String::from("bc")]);
+        let empty: Vec<String> = string_list![];
+        assert!(empty.is_empty());
+
+        let arena = string_list![#arena "a", "", "été"];
+        assert_eq!(arena.len(), 3);
+        assert_eq!(arena.iter().collect::<Vec<_>>(), ["a", "", "été"]);
+        assert_eq!(arena.range(2), Some(1..6));
+        assert_eq!(arena.get(3), None);
+        assert_eq!(format!("{arena:?}"), r#"["a", "", "été"]"#);
+        let empty: StringArena<0> = string_list![#arena];
+        assert!(empty.is_empty() && empty.as_str().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic]
+    fn arena_char_boundary() {
+        crate::StringArena::new("é", [1, 1]);
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(feature = "bevy")]
+use alloc::boxed::Box;
+#[cfg(feature = "bevy")]
+use bevy_ecs::system::{BoxedSystem, IntoSystem, SystemInput};
+
+/// Converts every element into a bevy [`System`](bevy_ecs::system::System)
+/// with [`IntoSystem`](bevy_ecs::system::IntoSystem) and collects them into a
+/// `Vec` of [`BoxedSystem`](bevy_ecs::system::BoxedSystem)s, for registering
+/// systems from a declarative list.
+///
+/// The elements can be system functions or closures. Put a wrapper before a
+/// `;` to apply it to every element first, such as a function that adds
+/// logging or pipes the output into an error handler.
+///
+/// Requires the `bevy` feature.
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use wrapped_list::wrapped_systems;
+///
+/// #[derive(Resource, Default)]
+/// struct Ticks(u32);
+///
+/// fn tick(mut ticks: ResMut<Ticks>) {
+///     ticks.0 += 1;
+/// }
+///
+/// let mut schedule = Schedule::default();
+/// for system in wrapped_systems![tick, tick] {
+///     schedule.add_systems(system);
+/// }
+///
+/// let mut world = World::new();
+/// world.init_resource::<Ticks>();
+/// schedule.run(&mut world);
+///
+/// assert_eq!(world.resource::<Ticks>().0, 2);
+/// ```
+#[cfg(feature = "bevy")]
+#[macro_export]
+macro_rules! wrapped_systems {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::__private::Vec::from([$($crate::__private::boxed_system($crate::__apply_wrapper![$wrapper $e])),*])
+    };
+    [$($wrapper:ident)::+ ! ; $($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_systems [] $($wrapper)::+ ! ; $($rest)*]
+    };
+    [$($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_systems [] $($wrapper)::+ ! => $($rest)*]
+    };
+    [$($e:expr),* $(,)?] => {
+        $crate::__private::Vec::from([$($crate::__private::boxed_system($e)),*])
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_systems [] $($rest)*]
+    };
+}
+
+/// Converts `system` into a [`BoxedSystem`] for
+/// [wrapped_systems](crate::wrapped_systems).
+#[cfg(feature = "bevy")]
+pub fn boxed_system<I, O, M, S>(system: S) -> BoxedSystem<I, O>
+where
+    I: SystemInput + 'static,
+    O: 'static,
+    S: IntoSystem<I, O, M>,
+{
+    Box::new(IntoSystem::into_system(system))
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "bevy"))]
+mod tests {
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::system::BoxedSystem;
+    use std::vec::Vec;
+
+    #[derive(Resource, Default)]
+    struct Total(u32);
+
+    fn add_one(mut total: ResMut<Total>) {
+        total.0 += 1;
+    }
+
+    fn add_ten(mut total: ResMut<Total>) {
+        total.0 += 10;
+    }
+
+    fn twice<M>(system: impl IntoSystem<(), (), M>) -> impl IntoSystem<(), (), ()> {
+        let mut system = IntoSystem::into_system(system);
+        let mut initialized = false;
+        IntoSystem::into_system(move |world: &mut World| {
+            if !initialized {
+                system.initialize(world);
+                initialized = true;
+            }
+            system.run((), world).unwrap();
+            system.run((), world).unwrap();
+        })
+    }
+
+    struct Adapters;
+
+    impl Adapters {
+        fn twice<M>(system: impl IntoSystem<(), (), M>) -> impl IntoSystem<(), (), ()> {
+            twice(system)
+        }
+    }
+
+    fn run(systems: Vec<BoxedSystem>) -> u32 {
+        let mut world = World::new();
+        world.init_resource::<Total>();
+        for system in systems {
+            let id = world.register_boxed_system(system);
+            world.run_system(id).unwrap();
+        }
+        world.resource::<Total>().0
+    }
+
+    #[test]
+    fn systems() {
+        let offset = 100;
+        let closure = move |mut total: ResMut<Total>| total.0 += offset;
+        assert_eq!(run(wrapped_systems![add_one, add_ten, closure]), 111);
+        assert_eq!(run(wrapped_systems![twice(add_one), add_ten]), 12);
+        assert_eq!(run(wrapped_systems![twice; add_one, add_ten]), 22);
+        assert_eq!(run(wrapped_systems![<Adapters>::twice => add_ten]), 20);
+        assert_eq!(run(wrapped_systems![twice;]), 0);
+    }
+}
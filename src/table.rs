@@ -0,0 +1,118 @@
+/// Defines `const fn`s returning wrapped tables, each with a module of the same
+/// name holding the table length as `LEN`.
+///
+/// The list after `=` is passed to [wrapped_list](crate::wrapped_list), and the
+/// `_` in the return type is replaced by the number of elements, so libraries
+/// get a documented access point for a generated table without spelling out its
+/// length. The wrapper must be a `const fn`. With groups of elements separated
+/// by `;`, the length is the number of groups. The `#range` modifier isn't
+/// supported, since its length isn't known until the range is expanded.
+///
+/// ```
+/// use wrapped_list::wrapped_table;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Op(u8);
+///
+/// impl Op {
+///     pub const fn new(code: u8) -> Self {
+///         Op(code)
+///     }
+/// }
+///
+/// wrapped_table! {
+///     /// Opcodes understood by the interpreter.
+///     pub const fn opcodes() -> [Op; _] = [Op::new; 0x01, 0x02, 0x03];
+/// }
+///
+/// assert_eq!(opcodes::LEN, 3);
+/// assert_eq!(opcodes()[2], Op(0x03));
+/// ```
+///
+/// ```compile_fail
+/// use wrapped_list::wrapped_table;
+///
+/// wrapped_table! {
+///     const fn codes() -> [u8; _] = [#range u8::from; 0..4];
+/// }
+/// ```
+#[macro_export]
+macro_rules! wrapped_table {
+    {$(
+        $(#[$attr:meta])*
+        $vis:vis const fn $name:ident() -> [$t:ty; _] = [$($list:tt)*];
+    )*} => {$(
+        $(#[$attr])*
+        $vis const fn $name() -> [$t; $name::LEN] {
+            $crate::wrapped_list![$($list)*]
+        }
+
+        #[doc = ::core::concat!("Length of the table returned by [`", ::core::stringify!($name), "`](", ::core::stringify!($name), "()).")]
+        $vis mod $name {
+            /// Number of elements in the table.
+            #[allow(dead_code)]
+            pub const LEN: usize = $crate::wrapped_table!(@len [] $($list)*);
+        }
+    )*};
+    (@len [] #range $($rest:tt)*) => {
+        ::core::compile_error!("`wrapped_table!` can't count the elements of a `#range`")
+    };
+    (@len [] # $modifier:ident $($rest:tt)*) => {
+        $crate::wrapped_table!(@len [] $($rest)*)
+    };
+    (@len [$($wrapper:tt)*] ; len = $len:expr ; $($rest:tt)*) => {
+        $crate::wrapped_table!(@len [$($wrapper)*] ; $($rest)*)
+    };
+    (@len [$($wrapper:tt)*] ; $($e:expr),* $(,)?) => {
+        <[&str]>::len(&[$(::core::stringify!($e)),*])
+    };
+    (@len [$($wrapper:tt)*] ; $($($e:expr),+ $(,)?);+) => {
+        <[&str]>::len(&[$(::core::stringify!($($e),+)),+])
+    };
+    (@len [$($wrapper:tt)*] => $($rest:tt)*) => {
+        $crate::wrapped_table!(@len [$($wrapper)*] ; $($rest)*)
+    };
+    (@len [$($wrapper:tt)*]) => {
+        ::core::compile_error!("expected `;` or `=>` followed by the elements")
+    };
+    (@len [$($wrapper:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::wrapped_table!(@len [$($wrapper)* $next] $($rest)*)
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    #[derive(Debug, PartialEq)]
+    struct Wrapper(i32);
+
+    const fn double(x: i32) -> i32 {
+        x * 2
+    }
+
+    wrapped_table! {
+        const fn wrappers() -> [Wrapper; _] = [Wrapper; 1, 2, 3];
+
+        /// Doubled values.
+        pub(crate) const fn doubled() -> [i32; _] = [double => 1, 2];
+        const fn empty() -> [Wrapper; _] = [Wrapper;];
+        const fn grid() -> [[i32; 3]; _] = [double; 1, 2, 3; 4, 5, 6];
+        const fn reversed() -> [Wrapper; _] = [#rev Wrapper => 1, 2];
+        const fn checked() -> [i32; _] = [double; len = 2; 1, 2];
+    }
+
+    const DOUBLED: [i32; doubled::LEN] = doubled();
+
+    #[test]
+    fn table() {
+        assert_eq!(wrappers(), [Wrapper(1), Wrapper(2), Wrapper(3)]);
+        assert_eq!(wrappers::LEN, 3);
+        assert_eq!(DOUBLED, [2, 4]);
+        assert_eq!(empty::LEN, 0);
+        assert!(empty().is_empty());
+        assert_eq!(grid::LEN, 2);
+        assert_eq!(grid(), [[2, 4, 6], [8, 10, 12]]);
+        assert_eq!(reversed(), [Wrapper(2), Wrapper(1)]);
+        assert_eq!(checked(), [2, 4]);
+    }
+}
@@ -0,0 +1,179 @@
+/// Applies an async wrapper to every element and spawns each resulting future
+/// on the Tokio runtime, returning a `Vec` of their
+/// [`JoinHandle`](tokio::task::JoinHandle)s in element order.
+///
+/// Prefix the wrapper with `#join_set` to spawn the futures onto a
+/// [`JoinSet`](tokio::task::JoinSet) instead.
+///
+/// Must be called from within a Tokio runtime. Requires the `tokio` feature.
+///
+/// ```
+/// use wrapped_list::spawned_list;
+///
+/// async fn double(x: u32) -> u32 {
+///     x * 2
+/// }
+///
+/// # let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+/// # runtime.block_on(async {
+/// let handles = spawned_list![double; 1, 2, 3];
+///
+/// let mut results = Vec::new();
+/// for handle in handles {
+///     results.push(handle.await.unwrap());
+/// }
+/// assert_eq!(results, [2, 4, 6]);
+///
+/// let mut set = spawned_list![#join_set double; 5];
+/// assert_eq!(set.join_next().await.unwrap().unwrap(), 10);
+/// # });
+/// ```
+#[cfg(feature = "tokio")]
+#[macro_export]
+macro_rules! spawned_list {
+    [#join_set $($rest:tt)*] => {{
+        let mut set = $crate::__private::tokio::task::JoinSet::new();
+        for future in $crate::__wrapped_futures![$($rest)*] {
+            set.spawn(future);
+        }
+        set
+    }};
+    [$($rest:tt)*] => {
+        $crate::__private::Vec::from_iter(
+            ::core::iter::IntoIterator::into_iter($crate::__wrapped_futures![$($rest)*])
+                .map($crate::__private::tokio::spawn),
+        )
+    };
+}
+
+/// Applies an async wrapper to every element and collects the boxed futures
+/// into a [`FuturesUnordered`](futures::stream::FuturesUnordered), ready to be
+/// polled with `while let Some(result) = set.next().await`.
+///
+/// Prefix the wrapper with `#vec` to get a `Vec` of the boxed futures instead,
+/// for use with `select_all` or `join_all`. The futures must be `Send`.
+///
+/// Requires the `futures` feature.
+///
+/// ```
+/// use futures::{executor::block_on, future::select_all, StreamExt};
+/// use wrapped_list::wrapped_unordered;
+///
+/// async fn double(x: u32) -> u32 {
+///     x * 2
+/// }
+///
+/// block_on(async {
+///     let mut set = wrapped_unordered![double; 1, 2, 3];
+///
+///     let mut sum = 0;
+///     while let Some(result) = set.next().await {
+///         sum += result;
+///     }
+///     assert_eq!(sum, 12);
+///
+///     let (first, _, _) = select_all(wrapped_unordered![#vec double; 5]).await;
+///     assert_eq!(first, 10);
+/// });
+/// ```
+#[cfg(feature = "futures")]
+#[macro_export]
+macro_rules! wrapped_unordered {
+    [#vec $($rest:tt)*] => {
+        $crate::__private::Vec::from_iter(
+            ::core::iter::IntoIterator::into_iter($crate::__wrapped_futures![$($rest)*])
+                .map($crate::__private::futures::future::FutureExt::boxed),
+        )
+    };
+    [$($rest:tt)*] => {
+        ::core::iter::Iterator::collect::<$crate::__private::futures::stream::FuturesUnordered<_>>(
+            ::core::iter::IntoIterator::into_iter($crate::__wrapped_futures![$($rest)*])
+                .map($crate::__private::futures::future::FutureExt::boxed),
+        )
+    };
+}
+
+#[doc(hidden)]
+#[cfg(any(feature = "tokio", feature = "futures"))]
+#[macro_export]
+macro_rules! __wrapped_futures {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        [$($crate::__apply_wrapper![$wrapper $e]),*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list __wrapped_futures [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, any(feature = "tokio", feature = "futures")))]
+mod tests {
+    use std::vec::Vec;
+
+    async fn square(x: u64) -> u64 {
+        x * x
+    }
+
+    macro_rules! square_later {
+        ($e:expr) => {
+            async move { $e * $e }
+        };
+    }
+
+    #[cfg(feature = "tokio")]
+    fn block_on<F: core::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn spawned_handles() {
+        block_on(async {
+            let mut results = Vec::new();
+            for handle in spawned_list![square; 1, 2, 3] {
+                results.push(handle.await.unwrap());
+            }
+            assert_eq!(results, [1, 4, 9]);
+
+            let handles = spawned_list![square_later! => 4u64];
+            assert_eq!(handles.len(), 1);
+            for handle in handles {
+                assert_eq!(handle.await.unwrap(), 16);
+            }
+        });
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn spawned_join_set() {
+        block_on(async {
+            let set = spawned_list![#join_set square; 1, 2, 3];
+            let mut results = set.join_all().await;
+            results.sort();
+            assert_eq!(results, [1, 4, 9]);
+        });
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn unordered() {
+        use futures::executor::block_on;
+        use futures::future::join_all;
+        use futures::StreamExt;
+
+        block_on(async {
+            let mut results: Vec<u64> = wrapped_unordered![square; 1, 2, 3].collect().await;
+            results.sort();
+            assert_eq!(results, [1, 4, 9]);
+
+            let set = wrapped_unordered![square_later! => 2u64];
+            assert_eq!(set.len(), 1);
+
+            let futures = wrapped_unordered![#vec square; 1, 2];
+            assert_eq!(join_all(futures).await, [1, 4]);
+        });
+    }
+}
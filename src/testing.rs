@@ -0,0 +1,330 @@
+/// Generates one `#[test]` function per element, each calling the wrapper
+/// with that element.
+///
+/// Cases are either `name => element` pairs, which become test functions of
+/// that name, or the names of constants, statics, or functions, which each
+/// get a module of the same name containing a `wrapped` test. The wrapper may
+/// return `()` or a `Result`.
+///
+/// ```
+/// use wrapped_list::wrapped_tests;
+///
+/// fn check_roundtrip(input: &str) {
+///     assert_eq!(input.parse::<i32>().unwrap().to_string(), input);
+/// }
+///
+/// wrapped_tests![check_roundtrip; small => "1", negative => "-20"];
+///
+/// const LARGE: &str = "1000000";
+///
+/// wrapped_tests![check_roundtrip; LARGE];
+/// ```
+#[macro_export]
+macro_rules! wrapped_tests {
+    [$wrapper:path ; $($name:ident => $e:expr),+ $(,)?] => {
+        $(
+            #[test]
+            fn $name() -> impl ::std::process::Termination {
+                $wrapper($e)
+            }
+        )+
+    };
+    [$wrapper:ident! ; $($name:ident => $e:expr),+ $(,)?] => {
+        $(
+            #[test]
+            fn $name() -> impl ::std::process::Termination {
+                $wrapper!($e)
+            }
+        )+
+    };
+    [$wrapper:path ; $($case:ident),+ $(,)?] => {
+        $(
+            #[allow(non_snake_case)]
+            mod $case {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #[test]
+                fn wrapped() -> impl ::std::process::Termination {
+                    $wrapper(super::$case)
+                }
+            }
+        )+
+    };
+    [$wrapper:ident! ; $($case:ident),+ $(,)?] => {
+        $(
+            #[allow(non_snake_case)]
+            mod $case {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #[test]
+                fn wrapped() -> impl ::std::process::Termination {
+                    $wrapper!(super::$case)
+                }
+            }
+        )+
+    };
+}
+
+/// Generates one criterion benchmark function per element, each measuring the
+/// wrapper called with that element.
+///
+/// The generated functions take `&mut Criterion` and can be passed to
+/// `criterion_group!`. The element is passed through `black_box` before it
+/// reaches the wrapper.
+///
+/// Requires the `criterion` feature.
+///
+/// ```
+/// use wrapped_list::wrapped_benches;
+///
+/// fn parse(input: &str) -> i32 {
+///     input.parse().unwrap()
+/// }
+///
+/// wrapped_benches![parse; parse_small => "1", parse_large => "1000000"];
+///
+/// // criterion_group!(benches, parse_small, parse_large);
+/// ```
+#[cfg(feature = "criterion")]
+#[macro_export]
+macro_rules! wrapped_benches {
+    [$wrapper:path ; $($name:ident => $e:expr),+ $(,)?] => {
+        $(
+            fn $name(c: &mut $crate::__private::criterion::Criterion) {
+                c.bench_function(::core::stringify!($name), |b| {
+                    b.iter(|| $wrapper(::core::hint::black_box($e)))
+                });
+            }
+        )+
+    };
+    [$wrapper:ident! ; $($name:ident => $e:expr),+ $(,)?] => {
+        $(
+            fn $name(c: &mut $crate::__private::criterion::Criterion) {
+                c.bench_function(::core::stringify!($name), |b| {
+                    b.iter(|| $wrapper!(::core::hint::black_box($e)))
+                });
+            }
+        )+
+    };
+}
+
+/// Asserts that a predicate wrapper returns `true` for every element, without
+/// writing a loop over a table of cases.
+///
+/// The elements are checked in order and the macro panics at the first one
+/// that fails, with its index and its source as written in the macro call.
+/// Prefix the wrapper with `#all` to check every element and report all of
+/// the failures together instead. Takes the same wrapper forms as
+/// [wrapped_list](crate::wrapped_list).
+///
+/// ```
+/// use wrapped_list::wrapped_assert_all;
+///
+/// fn is_even(n: i32) -> bool {
+///     n % 2 == 0
+/// }
+///
+/// wrapped_assert_all![is_even; 2, 4, 2 + 4];
+/// wrapped_assert_all![#all str::is_ascii; "hello", "world"];
+/// ```
+///
+/// ```should_panic
+/// use wrapped_list::wrapped_assert_all;
+///
+/// // Panics with "`char::is_alphabetic` returned false for element 1: `'2'`".
+/// wrapped_assert_all![char::is_alphabetic; 'a', '2', 'c'];
+/// ```
+#[macro_export]
+macro_rules! wrapped_assert_all {
+    [#all $($rest:tt)*] => {
+        $crate::__assert_all![all [] $($rest)*]
+    };
+    [$($rest:tt)*] => {
+        $crate::__assert_all![first [] $($rest)*]
+    };
+}
+
+/// Collects the wrapper tokens of
+/// [wrapped_assert_all](crate::wrapped_assert_all), then checks the elements
+/// one at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_all {
+    [@element [$($wrapper:tt)*] $e:expr] => {{
+        let [passed]: [bool; 1] = $crate::wrapped_list![$($wrapper)* ; $e];
+        passed
+    }};
+    [@name [$($wrapper:tt)*]] => {
+        ::core::stringify!($($wrapper)*)
+    };
+    [@emit first $wrapper:tt $($e:expr),*] => {{
+        #[allow(unused_mut, unused_variables)]
+        let mut index = 0usize;
+        $(
+            if !$crate::__assert_all![@element $wrapper $e] {
+                $crate::__private::assert_all_failed(
+                    $crate::__assert_all![@name $wrapper],
+                    &[(index, ::core::stringify!($e))],
+                );
+            }
+            #[allow(unused_assignments)]
+            {
+                index += 1;
+            }
+        )*
+    }};
+    [@emit all $wrapper:tt $($e:expr),*] => {{
+        #[allow(unused_mut)]
+        let mut failures = $crate::__private::Vec::<(usize, &str)>::new();
+        #[allow(unused_mut, unused_variables)]
+        let mut index = 0usize;
+        $(
+            if !$crate::__assert_all![@element $wrapper $e] {
+                failures.push((index, ::core::stringify!($e)));
+            }
+            #[allow(unused_assignments)]
+            {
+                index += 1;
+            }
+        )*
+        if !failures.is_empty() {
+            $crate::__private::assert_all_failed($crate::__assert_all![@name $wrapper], &failures);
+        }
+    }};
+    [$mode:ident [$($wrapper:tt)*] ; $($e:expr),* $(,)?] => {
+        $crate::__assert_all![@emit $mode [$($wrapper)*] $($e),*]
+    };
+    [$mode:ident [$($wrapper:tt)*] => $($rest:tt)*] => {
+        $crate::__assert_all![$mode [$($wrapper)*] ; $($rest)*]
+    };
+    [$mode:ident [$($wrapper:tt)*]] => {
+        ::core::compile_error!("expected `;` or `=>` followed by the elements")
+    };
+    [$mode:ident [$($wrapper:tt)*] $next:tt $($rest:tt)*] => {
+        $crate::__assert_all![$mode [$($wrapper)* $next] $($rest)*]
+    };
+}
+
+/// Panics with the elements that failed
+/// [wrapped_assert_all](crate::wrapped_assert_all), given as their index and
+/// source.
+#[track_caller]
+pub fn assert_all_failed(wrapper: &str, failures: &[(usize, &str)]) -> ! {
+    match failures {
+        [(index, source)] => {
+            panic!("`{wrapper}` returned false for element {index}: `{source}`")
+        }
+        _ => {
+            let mut message = std::format!(
+                "`{wrapper}` returned false for {} elements:",
+                failures.len()
+            );
+            for (index, source) in failures {
+                message.push_str(&std::format!("\n    element {index}: `{source}`"));
+            }
+            panic!("{message}")
+        }
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+    use std::string::{String, ToString};
+
+    fn check_roundtrip(input: &str) {
+        assert_eq!(input.parse::<i32>().unwrap().to_string(), input);
+    }
+
+    fn check_parse(input: &str) -> Result<(), ParseIntError> {
+        input.parse::<i32>().map(|_| ())
+    }
+
+    macro_rules! check_positive {
+        ($e:expr) => {
+            assert!($e > 0)
+        };
+    }
+
+    const SMALL: &str = "7";
+    const LARGE: &str = "1000000";
+    const POSITIVE: i32 = 3;
+
+    wrapped_tests![check_roundtrip; roundtrip_one => "1", roundtrip_negative => "-20",];
+    wrapped_tests![check_parse; parse_zero => "0"];
+    wrapped_tests![check_positive!; positive_literal => 1];
+    wrapped_tests![check_roundtrip; SMALL, LARGE];
+    wrapped_tests![check_positive!; POSITIVE];
+
+    fn is_positive(n: i32) -> bool {
+        n > 0
+    }
+
+    macro_rules! is_short {
+        ($e:expr) => {
+            $e.len() < 4
+        };
+    }
+
+    fn panic_message(f: impl FnOnce() + std::panic::UnwindSafe) -> String {
+        let payload = std::panic::catch_unwind(f).unwrap_err();
+        *payload.downcast::<String>().unwrap()
+    }
+
+    #[test]
+    fn assert_all() {
+        wrapped_assert_all![is_positive; 1, 2, POSITIVE];
+        wrapped_assert_all![#all is_short! => "a", "abc"];
+        wrapped_assert_all![is_positive;];
+
+        assert_eq!(
+            panic_message(|| wrapped_assert_all![is_positive; 1, -2, 1 - 5]),
+            "`is_positive` returned false for element 1: `-2`"
+        );
+        assert_eq!(
+            panic_message(|| wrapped_assert_all![#all is_positive; 1, -2, 1 - 5]),
+            "`is_positive` returned false for 2 elements:\n    \
+             element 1: `-2`\n    \
+             element 2: `1 - 5`"
+        );
+        assert_eq!(
+            panic_message(|| wrapped_assert_all![#all is_short!; "abcd"]),
+            "`is_short!` returned false for element 0: `\"abcd\"`"
+        );
+    }
+
+    #[cfg(feature = "criterion")]
+    mod criterion {
+        use crate::__private::criterion::Criterion;
+        use std::time::Duration;
+
+        fn parse(input: &str) -> i32 {
+            input.parse().unwrap()
+        }
+
+        macro_rules! double {
+            ($e:expr) => {
+                $e * 2
+            };
+        }
+
+        wrapped_benches![parse; parse_small => "1", parse_large => "1000000",];
+        wrapped_benches![double!; double_one => 1];
+
+        #[test]
+        fn benches() {
+            let mut c = Criterion::default()
+                .sample_size(10)
+                .warm_up_time(Duration::from_millis(1))
+                .measurement_time(Duration::from_millis(1))
+                .without_plots();
+            let benches: [fn(&mut Criterion); 3] = [parse_small, parse_large, double_one];
+            for bench in benches {
+                bench(&mut c);
+            }
+        }
+    }
+}
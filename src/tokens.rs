@@ -0,0 +1,78 @@
+/// Builds a `Vec<proc_macro2::TokenStream>` in which every element is
+/// interpolated into a call to the wrapper, for proc-macro authors generating
+/// repeated wrapped expressions.
+///
+/// The elements are values implementing `ToTokens`, such as `syn::Expr` or
+/// `proc_macro2::Ident`, and the wrapper is emitted as-is into the generated
+/// code. The result can be interpolated with `#(#tokens),*`.
+///
+/// Requires the `quote` feature.
+///
+/// ```
+/// use quote::{format_ident, quote};
+/// use wrapped_list::wrapped_tokens;
+///
+/// let (a, b) = (format_ident!("a"), format_ident!("b"));
+/// let tokens = wrapped_tokens![Box::new; a, b];
+///
+/// let list = quote!([#(#tokens),*]);
+/// assert_eq!(list.to_string(), quote!([Box::new(a), Box::new(b)]).to_string());
+/// ```
+#[cfg(feature = "quote")]
+#[macro_export]
+macro_rules! wrapped_tokens {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::__private::vec![$($crate::wrapped_tokens![@element $wrapper $e]),*]
+    };
+    [@element (fn $wrapper:expr) $e:expr] => {{
+        let element = &$e;
+        $crate::__private::quote::quote!($wrapper(#element))
+    }};
+    [@element (macro $($wrapper:ident)::+) $e:expr] => {{
+        let element = &$e;
+        $crate::__private::quote::quote!($($wrapper)::+!(#element))
+    }};
+    [@element (derive $wrapper:ty) $e:expr] => {
+        ::core::compile_error!("`derive` wrappers can't be quoted; use the wrapped type's constructor instead")
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_tokens [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "quote"))]
+mod tests {
+    use proc_macro2::{Literal, TokenStream};
+    use quote::{format_ident, quote};
+    use std::string::ToString;
+    use std::vec::Vec;
+
+    #[test]
+    fn tokens() {
+        let name = format_ident!("value");
+        let tokens = wrapped_tokens![Some; name, Literal::u8_unsuffixed(1)];
+        assert_eq!(
+            quote!(#(#tokens),*).to_string(),
+            quote!(Some(value), Some(1)).to_string()
+        );
+
+        let tokens = wrapped_tokens![vec! => quote!(1, 2)];
+        assert_eq!(tokens[0].to_string(), quote!(vec!(1, 2)).to_string());
+
+        let tokens = wrapped_tokens![std::println! => "hi"];
+        assert_eq!(
+            tokens[0].to_string(),
+            quote!(std::println!("hi")).to_string()
+        );
+
+        let tokens = wrapped_tokens![<Option<u8>>::Some; 1u8];
+        assert_eq!(
+            tokens[0].to_string().replace(' ', ""),
+            "<Option<u8>>::Some(1u8)"
+        );
+
+        let tokens: Vec<TokenStream> = wrapped_tokens![Some;];
+        assert!(tokens.is_empty());
+    }
+}
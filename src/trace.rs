@@ -0,0 +1,115 @@
+/// Expands to an array of wrapper calls that each run inside a `tracing` span
+/// recording the element index and its source expression.
+///
+/// Used by the `#traced` modifier of [wrapped_list](crate::wrapped_list) and
+/// [wrapped_vec](crate::wrapped_vec).
+#[doc(hidden)]
+#[cfg(feature = "tracing")]
+#[macro_export]
+macro_rules! __traced {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::__traced![@spans $((::core::stringify!($e)) $crate::__apply_wrapper![$wrapper $e]),*]
+    };
+    [@spans $(($source:expr) $call:expr),*] => {{
+        let mut index: usize = 0;
+        #[allow(unused_assignments)]
+        let list = [$({
+            let span = $crate::__private::tracing::info_span!("wrap", index, expr = $source);
+            let _entered = span.enter();
+            index += 1;
+            $call
+        }),*];
+        list
+    }};
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list __traced [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use core::fmt::Debug;
+    use std::boxed::Box;
+    use std::format;
+    use std::string::String;
+    use std::sync::{Arc, Mutex};
+    use std::vec::Vec;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Records the fields of every span that is created.
+    #[derive(Clone, Default)]
+    struct SpanRecorder(Arc<Mutex<Vec<String>>>);
+
+    struct FieldVisitor(String);
+
+    impl Visit for FieldVisitor {
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            self.0 += &format!("{}={:?} ", field.name(), value);
+        }
+    }
+
+    impl Subscriber for SpanRecorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            let mut visitor = FieldVisitor(String::new());
+            span.record(&mut visitor);
+            let mut spans = self.0.lock().unwrap();
+            spans.push(format!(
+                "{}: {}",
+                span.metadata().name(),
+                visitor.0.trim_end()
+            ));
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {}
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn parse(input: &str) -> i32 {
+        input.parse().unwrap()
+    }
+
+    #[test]
+    fn traced_spans() {
+        let recorder = SpanRecorder::default();
+        let list = tracing::subscriber::with_default(
+            recorder.clone(),
+            || wrapped_list![#traced parse; "1", &String::from("2")],
+        );
+        assert_eq!(list, [1, 2]);
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                r#"wrap: index=0 expr="\"1\"""#,
+                r#"wrap: index=1 expr="&String::from(\"2\")""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn traced_forms() {
+        macro_rules! double {
+            ($e:expr) => {
+                $e * 2
+            };
+        }
+
+        assert_eq!(wrapped_list![#traced double!; 1, 2], [2, 4]);
+        assert_eq!(wrapped_list![#traced derive Box<_> => 1], [Box::new(1)]);
+        assert_eq!(wrapped_vec![#traced Some => 1], [Some(1)]);
+        assert_eq!(
+            wrapped_vec![#traced <Option<i32>>::Some; 1, 2],
+            [Some(1), Some(2)]
+        );
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::WrappedList;
+
+/// Converts a collection of wrappers back into the values inside them.
+///
+/// [`unwrap_all`](UnwrapAll::unwrap_all) relies on `W: Into<T>`, while
+/// [`unwrap_all_with`](UnwrapAll::unwrap_all_with) takes an extractor for
+/// wrappers without such a conversion.
+///
+/// ```
+/// use wrapped_list::{wrapped_list, UnwrapAll};
+///
+/// struct Meters(f64);
+///
+/// impl From<Meters> for f64 {
+///     fn from(meters: Meters) -> f64 {
+///         meters.0
+///     }
+/// }
+///
+/// let distances: [f64; 2] = wrapped_list![Meters; 1.5, 2.0].unwrap_all();
+/// assert_eq!(distances, [1.5, 2.0]);
+///
+/// let values = vec![Box::new(1), Box::new(2)].unwrap_all_with(|boxed| *boxed);
+/// assert_eq!(values, [1, 2]);
+/// ```
+pub trait UnwrapAll<W> {
+    /// The same collection holding the inner values.
+    type Output<T>;
+
+    /// Converts every wrapper into its inner value with [`Into`].
+    fn unwrap_all<T>(self) -> Self::Output<T>
+    where
+        W: Into<T>;
+
+    /// Converts every wrapper into its inner value with `extract`.
+    fn unwrap_all_with<T, F>(self, extract: F) -> Self::Output<T>
+    where
+        F: FnMut(W) -> T;
+}
+
+impl<W, const N: usize> UnwrapAll<W> for [W; N] {
+    type Output<T> = [T; N];
+
+    fn unwrap_all<T>(self) -> [T; N]
+    where
+        W: Into<T>,
+    {
+        self.map(Into::into)
+    }
+
+    fn unwrap_all_with<T, F>(self, extract: F) -> [T; N]
+    where
+        F: FnMut(W) -> T,
+    {
+        self.map(extract)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<W> UnwrapAll<W> for Vec<W> {
+    type Output<T> = Vec<T>;
+
+    fn unwrap_all<T>(self) -> Vec<T>
+    where
+        W: Into<T>,
+    {
+        self.into_iter().map(Into::into).collect()
+    }
+
+    fn unwrap_all_with<T, F>(self, extract: F) -> Vec<T>
+    where
+        F: FnMut(W) -> T,
+    {
+        self.into_iter().map(extract).collect()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<W> UnwrapAll<W> for WrappedList<W> {
+    type Output<T> = Vec<T>;
+
+    fn unwrap_all<T>(self) -> Vec<T>
+    where
+        W: Into<T>,
+    {
+        self.into_vec().unwrap_all()
+    }
+
+    fn unwrap_all_with<T, F>(self, extract: F) -> Vec<T>
+    where
+        F: FnMut(W) -> T,
+    {
+        self.into_vec().unwrap_all_with(extract)
+    }
+}
+
+/// The inverse of [wrapped_list](crate::wrapped_list) for tuple structs:
+/// destructures every element with the wrapper's pattern and returns an array of
+/// the inner values.
+///
+/// The pattern must be irrefutable, so this works with single-field tuple structs
+/// but not with enum variants such as `Some`.
+///
+/// ```
+/// use wrapped_list::unwrapped_list;
+///
+/// struct Id(u32);
+///
+/// let first = Id(1);
+/// let ids = unwrapped_list![Id; first, Id(2)];
+///
+/// assert_eq!(ids, [1, 2]);
+/// ```
+#[macro_export]
+macro_rules! unwrapped_list {
+    [$wrapper:path ; $($e:expr),* $(,)?] => {
+        [$({
+            let $wrapper(inner) = $e;
+            inner
+        }),*]
+    };
+    [$wrapper:path => $($rest:tt)*] => {
+        $crate::unwrapped_list![$wrapper ; $($rest)*]
+    };
+    [$wrapper:path , $($rest:tt)*] => {
+        $crate::__wrapper_error!(comma)
+    };
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use super::UnwrapAll;
+    use std::boxed::Box;
+
+    #[derive(Debug, PartialEq, Eq)]
+    struct Wrapper(i32);
+
+    impl From<Wrapper> for i32 {
+        fn from(wrapper: Wrapper) -> i32 {
+            wrapper.0
+        }
+    }
+
+    #[test]
+    fn unwrap_all() {
+        let array: [i32; 3] = wrapped_list![Wrapper; 1, 2, 3].unwrap_all();
+        assert_eq!(array, [1, 2, 3]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unwrap_all_vec() {
+        use std::vec::Vec;
+
+        let vec: Vec<i32> = wrapped_vec![Wrapper; 1, 2].unwrap_all();
+        assert_eq!(vec, [1, 2]);
+        let list: Vec<i32> = wrapped_vec![#newtype Wrapper; 3].unwrap_all();
+        assert_eq!(list, [3]);
+        assert_eq!(
+            wrapped_vec![Some; 1, 2].unwrap_all_with(Option::unwrap),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn unwrap_all_with() {
+        assert_eq!(
+            wrapped_list![Box::new; 1, 2].unwrap_all_with(|boxed| *boxed),
+            [1, 2]
+        );
+    }
+
+    #[test]
+    fn unwrapped_list() {
+        let wrapper = Wrapper(1);
+        assert_eq!(unwrapped_list![Wrapper; wrapper, Wrapper(2)], [1, 2]);
+        assert_eq!(unwrapped_list![Wrapper => Wrapper(3)], [3]);
+        assert!(unwrapped_list![Wrapper;].map(|i: i32| i).is_empty());
+    }
+}
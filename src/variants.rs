@@ -0,0 +1,87 @@
+/// Fieldless enums whose variants can all be listed.
+///
+/// With the `proc-macro` feature this can be derived, which keeps the list in
+/// sync with the enum definition. See [wrapped_variants] for wrapping every
+/// variant.
+pub trait Variants: Sized {
+    /// The array type holding every variant, `[Self; N]`.
+    type Array;
+
+    /// Every variant, in declaration order.
+    const VARIANTS: Self::Array;
+}
+
+/// Wraps every variant of an enum implementing [`Variants`], in declaration
+/// order.
+///
+/// ```
+/// use wrapped_list::{wrapped_variants, Variants};
+///
+/// #[derive(Debug, PartialEq, Eq)]
+/// enum Suit {
+///     Hearts,
+///     Spades,
+/// }
+///
+/// // Or `#[derive(Variants)]` with the `proc-macro` feature.
+/// impl Variants for Suit {
+///     type Array = [Self; 2];
+///     const VARIANTS: [Self; 2] = [Suit::Hearts, Suit::Spades];
+/// }
+///
+/// let boxed = wrapped_variants![Box::new; Suit];
+///
+/// assert_eq!(boxed, [Box::new(Suit::Hearts), Box::new(Suit::Spades)]);
+/// ```
+#[macro_export]
+macro_rules! wrapped_variants {
+    [$wrapper:path ; $enum:ty] => {
+        <$enum as $crate::Variants>::VARIANTS.map($wrapper)
+    };
+    [$wrapper:ident! ; $enum:ty] => {
+        <$enum as $crate::Variants>::VARIANTS.map(|variant| $wrapper!(variant))
+    };
+    [$($wrapper:ident)::+ ! ; $enum:ty] => {{
+        use $($wrapper)::+ as __wrapper;
+        $crate::wrapped_variants![__wrapper!; $enum]
+    }};
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "proc-macro"))]
+mod tests {
+    use crate::Variants;
+    use std::boxed::Box;
+
+    #[derive(Variants, PartialEq, Eq, Debug, Clone, Copy)]
+    enum Direction {
+        North,
+        East,
+        South,
+        West,
+    }
+
+    #[derive(PartialEq, Eq, Debug)]
+    struct Wrapper(Direction);
+
+    macro_rules! wrapper_macro {
+        ($e:expr) => {
+            Wrapper($e)
+        };
+    }
+
+    #[test]
+    fn derive_variants() {
+        use Direction::*;
+
+        assert_eq!(Direction::VARIANTS, [North, East, South, West]);
+        assert_eq!(
+            wrapped_variants![Box::new; Direction],
+            [North, East, South, West].map(Box::new)
+        );
+        assert_eq!(
+            wrapped_variants![wrapper_macro!; Direction],
+            [Wrapper(North), Wrapper(East), Wrapper(South), Wrapper(West)]
+        );
+    }
+}
@@ -0,0 +1,58 @@
+/// Wraps every element, converts it with `JsValue::from`, and pushes it into a
+/// new [`js_sys::Array`], for passing literal lists across the wasm boundary.
+///
+/// Requires the `wasm-bindgen` feature.
+///
+/// ```no_run
+/// use js_sys::Array;
+/// use wrapped_list::wrapped_js_array;
+///
+/// fn scaled(x: f64) -> f64 {
+///     x * 2.0
+/// }
+///
+/// let array: Array = wrapped_js_array![scaled; 1.0, 2.5];
+///
+/// assert_eq!(array.length(), 2);
+/// ```
+#[cfg(feature = "wasm-bindgen")]
+#[macro_export]
+macro_rules! wrapped_js_array {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::wrapped_js_array![@push $($crate::__apply_wrapper![$wrapper $e]),*]
+    };
+    [@push $($value:expr),*] => {{
+        let array = $crate::__private::js_sys::Array::new();
+        $(
+            array.push(&$crate::__private::wasm_bindgen::JsValue::from($value));
+        )*
+        array
+    }};
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_js_array [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "wasm-bindgen"))]
+mod tests {
+    use std::string::String;
+
+    macro_rules! shout {
+        ($e:expr) => {
+            String::from($e) + "!"
+        };
+    }
+
+    // Imported JavaScript functions can only be called on wasm targets.
+    #[test]
+    #[cfg_attr(not(target_arch = "wasm32"), ignore)]
+    fn js_array() {
+        let numbers = wrapped_js_array![f64::from; 1u8, 2u8];
+        assert_eq!(numbers.length(), 2);
+        assert_eq!(numbers.get(1).as_f64(), Some(2.0));
+
+        let strings = wrapped_js_array![shout! => "a"];
+        assert_eq!(strings.get(0).as_string().as_deref(), Some("a!"));
+    }
+}
@@ -0,0 +1,186 @@
+#[cfg(feature = "egui")]
+use alloc::boxed::Box;
+
+/// A type-erased egui widget, as collected by
+/// [wrapped_widgets](crate::wrapped_widgets). It implements
+/// [`Widget`](egui::Widget), so it can be passed to `ui.add`.
+///
+/// Requires the `egui` feature.
+#[cfg(feature = "egui")]
+pub type BoxedWidget<'a> = Box<dyn FnOnce(&mut egui::Ui) -> egui::Response + 'a>;
+
+/// Boxes every element into a [`BoxedWidget`](crate::BoxedWidget), so widgets
+/// of different types can be collected into a single `Vec` and added in a loop.
+///
+/// Put a wrapper before a `;` to apply it to every element first, such as a
+/// widget constructor.
+///
+/// Requires the `egui` feature.
+///
+/// ```
+/// use egui::{Button, Label};
+/// use wrapped_list::wrapped_widgets;
+///
+/// let toolbar = wrapped_widgets![Label::new("Tools"), Button::new("Save"), Button::new("Load")];
+/// let labels = wrapped_widgets![Label::new; "a", "b"];
+///
+/// let ctx = egui::Context::default();
+/// let mut widgets = Some(toolbar.into_iter().chain(labels));
+/// let mut output = ctx.run_ui(Default::default(), |ui| {
+///     for widget in widgets.take().into_iter().flatten() {
+///         ui.add(widget);
+///     }
+/// });
+/// output.textures_delta.clear();
+/// ```
+#[cfg(feature = "egui")]
+#[macro_export]
+macro_rules! wrapped_widgets {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::__private::Vec::from([$($crate::__private::boxed_widget($crate::__apply_wrapper![$wrapper $e])),*])
+    };
+    [$($wrapper:ident)::+ ! ; $($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_widgets [] $($wrapper)::+ ! ; $($rest)*]
+    };
+    [$($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_widgets [] $($wrapper)::+ ! => $($rest)*]
+    };
+    [$($e:expr),* $(,)?] => {
+        $crate::__private::Vec::from([$($crate::__private::boxed_widget($e)),*])
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_widgets [] $($rest)*]
+    };
+}
+
+/// Boxes `widget` for [wrapped_widgets](crate::wrapped_widgets).
+#[cfg(feature = "egui")]
+pub fn boxed_widget<'a>(widget: impl egui::Widget + 'a) -> BoxedWidget<'a> {
+    Box::new(move |ui: &mut egui::Ui| ui.add(widget))
+}
+
+/// Converts every element into an iced [`Element`](iced_core::Element), so
+/// widgets of different types can be collected into a single `Vec` of
+/// children for a row or column.
+///
+/// Put a wrapper before a `;` to apply it to every element first, such as a
+/// widget constructor. The message, theme, and renderer types are inferred
+/// from how the list is used.
+///
+/// Requires the `iced` feature.
+///
+/// ```
+/// use iced_core::widget::Text;
+/// use iced_core::{Element, Theme};
+/// use wrapped_list::wrapped_elements;
+///
+/// let children: Vec<Element<'_, (), Theme, ()>> =
+///     wrapped_elements![Text::new("Name"), "Value"];
+/// let labels: Vec<Element<'_, (), Theme, ()>> = wrapped_elements![Text::new; "a", "b"];
+///
+/// assert_eq!(children.len() + labels.len(), 4);
+/// ```
+#[cfg(feature = "iced")]
+#[macro_export]
+macro_rules! wrapped_elements {
+    [@wrap [] $wrapper:tt $($e:expr),* $(,)?] => {
+        $crate::__private::Vec::from([$($crate::__private::iced_core::Element::from($crate::__apply_wrapper![$wrapper $e])),*])
+    };
+    [$($wrapper:ident)::+ ! ; $($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_elements [] $($wrapper)::+ ! ; $($rest)*]
+    };
+    [$($wrapper:ident)::+ ! => $($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_elements [] $($wrapper)::+ ! => $($rest)*]
+    };
+    [$($e:expr),* $(,)?] => {
+        $crate::__private::Vec::from([$($crate::__private::iced_core::Element::from($e)),*])
+    };
+    [$($rest:tt)*] => {
+        $crate::__parse_wrapper![list wrapped_elements [] $($rest)*]
+    };
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "egui"))]
+mod egui_tests {
+    use egui::{Button, Label, Response, Ui};
+    use std::cell::Cell;
+    use std::vec::Vec;
+
+    struct Counted<'a>(&'a Cell<u32>);
+
+    impl egui::Widget for Counted<'_> {
+        fn ui(self, ui: &mut Ui) -> Response {
+            self.0.set(self.0.get() + 1);
+            ui.label("counted")
+        }
+    }
+
+    macro_rules! counted {
+        ($e:expr) => {
+            Counted($e)
+        };
+    }
+
+    fn show(widgets: Vec<crate::BoxedWidget<'_>>) -> usize {
+        let mut widgets = Some(widgets);
+        let mut shown = 0;
+        let mut output = egui::Context::default().run_ui(Default::default(), |ui| {
+            for widget in widgets.take().into_iter().flatten() {
+                ui.add(widget);
+                shown += 1;
+            }
+        });
+        output.textures_delta.clear();
+        shown
+    }
+
+    #[test]
+    fn widgets() {
+        let calls = Cell::new(0);
+        let shown = show(wrapped_widgets![
+            Label::new("a"),
+            Button::new("b"),
+            Counted(&calls),
+            |ui: &mut Ui| ui.separator(),
+        ]);
+        assert_eq!((shown, calls.get()), (4, 1));
+        assert_eq!(show(wrapped_widgets![Counted; &calls, &calls]), 2);
+        assert_eq!(show(wrapped_widgets![counted! => &calls]), 1);
+        assert_eq!(calls.get(), 4);
+        assert_eq!(show(wrapped_widgets![Label::new;]), 0);
+    }
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "iced"))]
+mod iced_tests {
+    use iced_core::widget::Text;
+    use iced_core::{Element, Theme};
+    use std::vec::Vec;
+
+    type Children<'a> = Vec<Element<'a, (), Theme, ()>>;
+
+    struct Labels;
+
+    impl Labels {
+        fn bold(content: &str) -> Text<'_, Theme, ()> {
+            Text::new(content).font(iced_core::Font {
+                weight: iced_core::font::Weight::Bold,
+                ..iced_core::Font::DEFAULT
+            })
+        }
+    }
+
+    #[test]
+    fn elements() {
+        let children: Children = wrapped_elements![Text::new("a"), "b", Labels::bold("c")];
+        assert_eq!(children.len(), 3);
+        let children: Children = wrapped_elements![Text::new => "a", "b"];
+        assert_eq!(children.len(), 2);
+        let children: Children = wrapped_elements![<Labels>::bold; "a"];
+        assert_eq!(children.len(), 1);
+        let children: Children = wrapped_elements![Text::new;];
+        assert!(children.is_empty());
+    }
+}
@@ -0,0 +1,271 @@
+use core::cell::{Cell, RefCell};
+use core::cmp::Reverse;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, TryFromIntError, Wrapping,
+};
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+use alloc::sync::Arc;
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    ffi::{CString, NulError},
+    rc::Rc,
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::sync::{Mutex, RwLock};
+
+/// Types that know how to wrap a single value.
+///
+/// This is implemented for the common standard library wrappers such as
+/// `Box`, `Rc`, `Arc`, `Option`, `Result`, `Cell`, and `RefCell`. With the
+/// `proc-macro` feature it can be derived for single-field structs and for
+/// enums whose variants each have a single field.
+///
+/// The list macros call it when the wrapper is prefixed with `derive`:
+///
+/// ```
+/// use std::rc::Rc;
+/// use wrapped_list::{wrapped_list, Wrap};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Meters(f32);
+///
+/// impl Wrap<f32> for Meters {
+///     type Output = Self;
+///
+///     fn wrap(value: f32) -> Self {
+///         Meters(value)
+///     }
+/// }
+///
+/// let distances = wrapped_list![derive Meters; 1.0, 2.5];
+/// assert_eq!(distances, [Meters(1.0), Meters(2.5)]);
+///
+/// let shared = wrapped_list![derive Rc<_>; 1, 2];
+/// assert_eq!(shared, [Rc::new(1), Rc::new(2)]);
+/// ```
+pub trait Wrap<T> {
+    /// The wrapped value.
+    type Output;
+
+    /// Wraps `value`.
+    fn wrap(value: T) -> Self::Output;
+}
+
+macro_rules! impl_wrap {
+    ($($(#[$attr:meta])* $ty:ty => $wrap:expr;)*) => {
+        $(
+            $(#[$attr])*
+            impl<T> Wrap<T> for $ty {
+                type Output = Self;
+
+                fn wrap(value: T) -> Self {
+                    $wrap(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_wrap! {
+    Option<T> => Some;
+    Cell<T> => Cell::new;
+    RefCell<T> => RefCell::new;
+    Reverse<T> => Reverse;
+    Wrapping<T> => Wrapping;
+    #[cfg(feature = "alloc")]
+    Box<T> => Box::new;
+    #[cfg(feature = "alloc")]
+    Rc<T> => Rc::new;
+    #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+    Arc<T> => Arc::new;
+    #[cfg(feature = "std")]
+    Mutex<T> => Mutex::new;
+    #[cfg(feature = "std")]
+    RwLock<T> => RwLock::new;
+}
+
+impl<T, E> Wrap<T> for Result<T, E> {
+    type Output = Self;
+
+    fn wrap(value: T) -> Self {
+        Ok(value)
+    }
+}
+
+/// Types that can wrap a single value, but may reject it.
+///
+/// This is implemented for the `NonZero*` integers and `CString`, and is used by
+/// [wrapped_try_vec](crate::wrapped_try_vec) when the wrapper is prefixed with
+/// `derive`:
+///
+/// ```
+/// use std::num::NonZeroU8;
+/// use wrapped_list::{wrapped_try_vec, TryWrap};
+///
+/// let counts = wrapped_try_vec![derive NonZeroU8; 1, 2];
+/// assert_eq!(counts, Ok(vec![NonZeroU8::MIN, NonZeroU8::new(2).unwrap()]));
+///
+/// assert!(NonZeroU8::try_wrap(0).is_err());
+/// ```
+pub trait TryWrap<T>: Sized {
+    /// The error returned when `value` can't be wrapped.
+    type Error;
+
+    /// Wraps `value`, or returns an error if it isn't valid for `Self`.
+    fn try_wrap(value: T) -> Result<Self, Self::Error>;
+}
+
+macro_rules! impl_try_wrap_nonzero {
+    ($($ty:ty => $int:ty),* $(,)?) => {
+        $(
+            impl TryWrap<$int> for $ty {
+                type Error = TryFromIntError;
+
+                fn try_wrap(value: $int) -> Result<Self, TryFromIntError> {
+                    <$ty>::try_from(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_try_wrap_nonzero! {
+    NonZeroU8 => u8,
+    NonZeroU16 => u16,
+    NonZeroU32 => u32,
+    NonZeroU64 => u64,
+    NonZeroU128 => u128,
+    NonZeroUsize => usize,
+    NonZeroI8 => i8,
+    NonZeroI16 => i16,
+    NonZeroI32 => i32,
+    NonZeroI64 => i64,
+    NonZeroI128 => i128,
+    NonZeroIsize => isize,
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Into<Vec<u8>>> TryWrap<T> for CString {
+    type Error = NulError;
+
+    fn try_wrap(value: T) -> Result<Self, NulError> {
+        CString::new(value)
+    }
+}
+
+#[doc(hidden)]
+#[cfg(test)]
+mod tests {
+    use crate::Wrap;
+    use std::cell::RefCell;
+
+    #[test]
+    fn std_wrappers() {
+        assert_eq!(<Option<_> as Wrap<_>>::wrap(1), Some(1));
+        assert_eq!(<Result<_, ()> as Wrap<_>>::wrap(1), Ok(1));
+        assert_eq!(
+            wrapped_tuple!(derive RefCell<_>; 1, "a"),
+            (RefCell::new(1), RefCell::new("a"))
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn alloc_wrappers() {
+        use std::boxed::Box;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        assert_eq!(<Box<_> as Wrap<_>>::wrap(1), Box::new(1));
+        assert_eq!(wrapped_list![derive Rc<_>; 1, 2], [Rc::new(1), Rc::new(2)]);
+        assert_eq!(wrapped_list![derive Arc<_> => 1], [Arc::new(1)]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn std_locks() {
+        use std::sync::Mutex;
+
+        let locks = wrapped_list![derive Mutex<_>; 1, 2];
+        assert_eq!(*locks[1].lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn try_wrap() {
+        use crate::TryWrap;
+        use std::num::{NonZeroI32, NonZeroUsize};
+
+        assert_eq!(NonZeroUsize::try_wrap(3), Ok(NonZeroUsize::new(3).unwrap()));
+        assert!(NonZeroI32::try_wrap(0).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn try_wrap_cstring() {
+        use crate::TryWrap;
+        use std::ffi::CString;
+
+        assert_eq!(CString::try_wrap("a").unwrap().as_bytes(), b"a");
+        assert!(CString::try_wrap("a\0b").is_err());
+    }
+}
+
+#[doc(hidden)]
+#[cfg(all(test, feature = "proc-macro"))]
+mod derive_tests {
+    use crate::Wrap;
+    use std::string::{String, ToString};
+
+    #[derive(Wrap, PartialEq, Eq, Debug)]
+    struct Id(u32);
+
+    #[derive(Wrap, PartialEq, Eq, Debug)]
+    struct Named {
+        inner: u32,
+    }
+
+    #[derive(Wrap, PartialEq, Eq, Debug)]
+    struct Generic<T>(T);
+
+    #[derive(Wrap, PartialEq, Eq, Debug)]
+    enum Value {
+        Int(i64),
+        Text { text: String },
+    }
+
+    #[test]
+    fn derive_struct() {
+        assert_eq!(Id::wrap(1), Id(1));
+        assert_eq!(Named::wrap(1), Named { inner: 1 });
+        assert_eq!(Generic::wrap("a"), Generic("a"));
+    }
+
+    #[test]
+    fn derive_enum() {
+        assert_eq!(Value::wrap(1), Value::Int(1));
+        assert_eq!(
+            Value::wrap("a".to_string()),
+            Value::Text {
+                text: "a".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn derive_list() {
+        assert_eq!(wrapped_list![derive Id; 1, 2], [Id(1), Id(2)]);
+        assert_eq!(
+            wrapped_list![derive Generic<_> => 1, 2],
+            [Generic(1), Generic(2)]
+        );
+        assert_eq!(wrapped_tuple!(derive Named; 1), (Named { inner: 1 },));
+        assert_eq!(
+            wrapped_vec![derive Value; 1, 2],
+            [Value::Int(1), Value::Int(2)]
+        );
+    }
+}
@@ -0,0 +1,188 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, LitStr, Token,
+};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// A comma-separated list of `YYYY-MM-DD` or `YYYY-MM-DD HH:MM:SS` literals.
+pub struct DateList {
+    dates: Punctuated<LitStr, Token![,]>,
+}
+
+impl Parse for DateList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(DateList {
+            dates: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+struct DateTime {
+    year: i32,
+    month: u8,
+    day: u8,
+    time: Option<(u8, u8, u8)>,
+}
+
+impl DateList {
+    /// Validates every literal and expands to an array of `time::Date`s, or of
+    /// `time::PrimitiveDateTime`s when the literals include a time of day.
+    pub fn expand(self) -> syn::Result<TokenStream> {
+        let mut errors: Option<Error> = None;
+        let mut parsed = Vec::new();
+        for lit in &self.dates {
+            match parse_date_time(&lit.value()) {
+                Ok(date) => parsed.push((lit, date)),
+                Err(message) => {
+                    let error = Error::new(lit.span(), message);
+                    match &mut errors {
+                        Some(errors) => errors.combine(error),
+                        None => errors = Some(error),
+                    }
+                }
+            }
+        }
+        if let Some(errors) = errors {
+            return Err(errors);
+        }
+
+        if let Some((first, _)) = parsed.first() {
+            let with_time = parsed[0].1.time.is_some();
+            if let Some((lit, _)) = parsed.iter().find(|(_, d)| d.time.is_some() != with_time) {
+                let mut error = Error::new(
+                    lit.span(),
+                    "all literals must either have a time of day or none",
+                );
+                error.combine(Error::new(first.span(), "the first literal is here"));
+                return Err(error);
+            }
+        }
+
+        let dates = parsed.iter().map(|(lit, date)| {
+            let DateTime {
+                year, month, day, ..
+            } = *date;
+            let month = format_ident!("{}", MONTHS[month as usize - 1]);
+            let value = quote_spanned! {lit.span()=>
+                ::wrapped_list::__private::time::Date::from_calendar_date(
+                    #year,
+                    ::wrapped_list::__private::time::Month::#month,
+                    #day,
+                )
+            };
+            let value = match date.time {
+                None => value,
+                Some((hour, minute, second)) => quote_spanned! {lit.span()=>
+                    match ::wrapped_list::__private::time::Time::from_hms(#hour, #minute, #second) {
+                        ::core::result::Result::Ok(time) => match #value {
+                            ::core::result::Result::Ok(date) => ::core::result::Result::Ok(
+                                ::wrapped_list::__private::time::PrimitiveDateTime::new(date, time),
+                            ),
+                            ::core::result::Result::Err(error) => ::core::result::Result::Err(error),
+                        },
+                        ::core::result::Result::Err(error) => ::core::result::Result::Err(error),
+                    }
+                },
+            };
+            quote_spanned! {lit.span()=>
+                const {
+                    match #value {
+                        ::core::result::Result::Ok(value) => value,
+                        ::core::result::Result::Err(_) => ::core::panic!("invalid date"),
+                    }
+                }
+            }
+        });
+
+        Ok(quote!([#(#dates),*]))
+    }
+}
+
+/// Parses `YYYY-MM-DD`, optionally followed by ` HH:MM:SS` or `THH:MM:SS`.
+fn parse_date_time(value: &str) -> Result<DateTime, String> {
+    let (date, time) = match value.split_once([' ', 'T']) {
+        Some((date, time)) => (date, Some(time)),
+        None => (value, None),
+    };
+
+    let fields: Vec<&str> = date.split('-').collect();
+    let [year, month, day] = fields[..] else {
+        return Err(format!(
+            "expected a date formatted as `YYYY-MM-DD`, found `{value}`"
+        ));
+    };
+    let year: i32 = parse_field(year, 4, "year")?;
+    let month: u8 = parse_field(month, 2, "month")?;
+    let day: u8 = parse_field(day, 2, "day")?;
+    if !(1..=12).contains(&month) {
+        return Err(format!("month {month} is out of range, expected 1 to 12"));
+    }
+    let days = days_in_month(year, month);
+    if !(1..=days).contains(&day) {
+        return Err(format!(
+            "day {day} is out of range for {} {year}, expected 1 to {days}",
+            MONTHS[month as usize - 1]
+        ));
+    }
+
+    let time = match time {
+        None => None,
+        Some(time) => {
+            let fields: Vec<&str> = time.split(':').collect();
+            let [hour, minute, second] = fields[..] else {
+                return Err(format!(
+                    "expected a time formatted as `HH:MM:SS`, found `{time}`"
+                ));
+            };
+            let hour: u8 = parse_field(hour, 2, "hour")?;
+            let minute: u8 = parse_field(minute, 2, "minute")?;
+            let second: u8 = parse_field(second, 2, "second")?;
+            if hour > 23 || minute > 59 || second > 59 {
+                return Err(format!("`{time}` is not a valid time of day"));
+            }
+            Some((hour, minute, second))
+        }
+    };
+
+    Ok(DateTime {
+        year,
+        month,
+        day,
+        time,
+    })
+}
+
+fn parse_field<T: std::str::FromStr>(field: &str, digits: usize, name: &str) -> Result<T, String> {
+    if field.len() != digits || !field.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(format!("expected a {digits}-digit {name}, found `{field}`"));
+    }
+    field
+        .parse()
+        .map_err(|_| format!("invalid {name} `{field}`"))
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
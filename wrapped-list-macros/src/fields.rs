@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Index, Member};
+
+/// Implements `wrapped_list::Fields` for a struct.
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`Fields` can only be derived for structs",
+        ));
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let members: Vec<Member> = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(i)),
+        })
+        .collect();
+    let names = members.iter().map(|member| match member {
+        Member::Named(ident) => ident.to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::wrapped_list::Fields for #name #ty_generics #where_clause {
+            fn wrapped_fields<W>(
+                &self,
+                mut wrapper: impl FnMut(&dyn ::core::any::Any) -> W,
+            ) -> ::wrapped_list::__private::Vec<(&'static str, W)> {
+                ::wrapped_list::__private::vec![#((#names, wrapper(&self.#members))),*]
+            }
+        }
+    })
+}
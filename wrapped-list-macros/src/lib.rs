@@ -0,0 +1,124 @@
+//! Procedural macros for the [wrapped-list](https://docs.rs/wrapped-list) crate.
+//!
+//! These macros are re-exported by `wrapped-list` behind its `proc-macro`
+//! feature and are meant to be used through that crate.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, punctuated::Punctuated, DeriveInput, Expr, Item, ItemFn, Token};
+
+mod date_list;
+mod fields;
+#[cfg(feature = "regex")]
+mod regex_list;
+mod variants;
+mod wrap;
+mod wrap_args;
+mod wrapped;
+mod wrapped_array;
+mod wrapped_submit;
+
+/// Procedural counterpart of `wrapped_list!` with span-accurate errors and
+/// support for arbitrary wrapper expressions.
+#[proc_macro]
+pub fn wrapped(input: TokenStream) -> TokenStream {
+    syn::parse::<wrapped::Wrapped>(input)
+        .map_or_else(expression_error, |wrapped| wrapped.expand())
+        .into()
+}
+
+/// Validates a list of date literals at compile time and expands to an array
+/// of `time::Date` or `time::PrimitiveDateTime` values.
+#[proc_macro]
+pub fn date_list(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as date_list::DateList)
+        .expand()
+        .unwrap_or_else(expression_error)
+        .into()
+}
+
+/// Validates a list of regex patterns at compile time and expands to an array
+/// of lazily compiled `Regex`es.
+#[cfg(feature = "regex")]
+#[proc_macro]
+pub fn regex_list(input: TokenStream) -> TokenStream {
+    parse_macro_input!(input as regex_list::RegexList)
+        .expand()
+        .unwrap_or_else(expression_error)
+        .into()
+}
+
+/// Fails with a "duplicate key" error if two of the given literal keys have
+/// the same value, and expands to nothing otherwise. Used by the map macros of
+/// `wrapped-list`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn unique_keys(input: TokenStream) -> TokenStream {
+    let keys = parse_macro_input!(input with Punctuated::<Expr, Token![,]>::parse_terminated);
+    wrapped::check_unique(&keys, "key")
+        .map_or_else(syn::Error::into_compile_error, |()| {
+            proc_macro2::TokenStream::new()
+        })
+        .into()
+}
+
+/// Wraps every element of the array literal assigned to a `static` or `const`
+/// item.
+#[proc_macro_attribute]
+pub fn wrapped_array(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let wrapper = parse_macro_input!(attr as wrapped::Wrapper);
+    wrapped_array::expand(wrapper, parse_macro_input!(item as Item))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Adds a wrapped copy of a `static` item to a registry declared with
+/// `wrapped_list::wrapped_distributed_slice!`.
+#[proc_macro_attribute]
+pub fn wrapped_submit(attr: TokenStream, item: TokenStream) -> TokenStream {
+    parse_macro_input!(attr as wrapped_submit::Submit)
+        .expand(parse_macro_input!(item as Item))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Lets a function accept unwrapped values for parameters marked with
+/// `#[wrap(T)]`, wrapping them before the body runs.
+#[proc_macro_attribute]
+pub fn wrap_args(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let wrapper = parse_macro_input!(attr as wrapped::Wrapper);
+    wrap_args::expand(wrapper, parse_macro_input!(item as ItemFn))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `wrapped_list::Wrap` for single-field structs and for enums whose
+/// variants each have a single field.
+#[proc_macro_derive(Wrap)]
+pub fn derive_wrap(input: TokenStream) -> TokenStream {
+    wrap::derive(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `wrapped_list::Variants` for fieldless enums.
+#[proc_macro_derive(Variants)]
+pub fn derive_variants(input: TokenStream) -> TokenStream {
+    variants::derive(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `wrapped_list::Fields` for structs.
+#[proc_macro_derive(Fields)]
+pub fn derive_fields(input: TokenStream) -> TokenStream {
+    fields::derive(parse_macro_input!(input as DeriveInput))
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Emits `error` as a block, so that several combined errors are still valid in
+/// expression position.
+fn expression_error(error: syn::Error) -> proc_macro2::TokenStream {
+    let errors = error.into_compile_error();
+    quote::quote!({ #errors })
+}
@@ -0,0 +1,49 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Error, LitStr, Token,
+};
+
+/// A comma-separated list of regex pattern literals.
+pub struct RegexList {
+    patterns: Punctuated<LitStr, Token![,]>,
+}
+
+impl Parse for RegexList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(RegexList {
+            patterns: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+impl RegexList {
+    /// Checks every pattern and expands to an array of lazily compiled
+    /// `Regex`es, reporting every invalid pattern at its literal.
+    pub fn expand(self) -> syn::Result<TokenStream> {
+        let mut errors: Option<Error> = None;
+        for pattern in &self.patterns {
+            if let Err(e) = regex_syntax::Parser::new().parse(&pattern.value()) {
+                let error = Error::new(pattern.span(), format!("invalid regex: {e}"));
+                match &mut errors {
+                    Some(errors) => errors.combine(error),
+                    None => errors = Some(error),
+                }
+            }
+        }
+        if let Some(errors) = errors {
+            return Err(errors);
+        }
+
+        let patterns = self.patterns.iter();
+        Ok(quote! {
+            [#(
+                ::std::sync::LazyLock::<::wrapped_list::__private::regex::Regex>::new(|| {
+                    ::wrapped_list::__private::regex::Regex::new(#patterns).unwrap()
+                })
+            ),*]
+        })
+    }
+}
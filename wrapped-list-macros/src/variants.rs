@@ -0,0 +1,36 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields};
+
+/// Implements `wrapped_list::Variants` for a fieldless enum.
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "`Variants` can only be derived for enums",
+        ));
+    };
+
+    if let Some(variant) = data
+        .variants
+        .iter()
+        .find(|v| !matches!(v.fields, Fields::Unit))
+    {
+        return Err(Error::new(
+            variant.fields.span(),
+            "`Variants` can only be derived for enums without fields",
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let len = data.variants.len();
+    let variants = data.variants.iter().map(|v| &v.ident);
+
+    Ok(quote! {
+        impl #impl_generics ::wrapped_list::Variants for #name #ty_generics #where_clause {
+            type Array = [Self; #len];
+            const VARIANTS: [Self; #len] = [#(Self::#variants),*];
+        }
+    })
+}
@@ -0,0 +1,72 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{spanned::Spanned, Data, DeriveInput, Error, Fields, Type};
+
+/// Implements `wrapped_list::Wrap` for a single-field struct, or once per
+/// variant for an enum whose variants each have a single field.
+pub fn derive(input: DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let constructors = match &input.data {
+        Data::Struct(data) => vec![constructor(&data.fields, quote!(Self))?],
+        Data::Enum(data) => {
+            let mut constructors: Vec<(Type, TokenStream)> = Vec::new();
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let (ty, ctor) = constructor(&variant.fields, quote!(Self::#ident))?;
+                if constructors.iter().any(|(other, _)| same_type(other, &ty)) {
+                    return Err(Error::new(
+                        ty.span(),
+                        "another variant already wraps this type, so `Wrap` would be ambiguous",
+                    ));
+                }
+                constructors.push((ty, ctor));
+            }
+            constructors
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "`Wrap` cannot be derived for unions",
+            ))
+        }
+    };
+
+    let impls = constructors.iter().map(|(ty, ctor)| {
+        quote! {
+            impl #impl_generics ::wrapped_list::Wrap<#ty> for #name #ty_generics #where_clause {
+                type Output = Self;
+
+                fn wrap(value: #ty) -> Self {
+                    #ctor
+                }
+            }
+        }
+    });
+
+    Ok(quote!(#(#impls)*))
+}
+
+/// Returns the wrapped field type and an expression building the value from
+/// a `value` binding.
+fn constructor(fields: &Fields, path: TokenStream) -> syn::Result<(Type, TokenStream)> {
+    match fields {
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
+            Ok((unnamed.unnamed[0].ty.clone(), quote!(#path(value))))
+        }
+        Fields::Named(named) if named.named.len() == 1 => {
+            let field = &named.named[0];
+            let ident = &field.ident;
+            Ok((field.ty.clone(), quote!(#path { #ident: value })))
+        }
+        _ => Err(Error::new(
+            fields.span(),
+            "`Wrap` can only be derived for types and variants with exactly one field",
+        )),
+    }
+}
+
+fn same_type(a: &Type, b: &Type) -> bool {
+    quote!(#a).to_string() == quote!(#b).to_string()
+}
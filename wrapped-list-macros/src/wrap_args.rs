@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_quote, spanned::Spanned, Error, FnArg, GenericArgument, ItemFn, Pat, PathArguments, Stmt,
+    Type,
+};
+
+use crate::wrapped::Wrapper;
+
+/// Rewrites every parameter marked with `#[wrap(T)]` to accept unwrapped `T`
+/// values, wrapping them at the top of the function body.
+pub fn expand(wrapper: Wrapper, mut item: ItemFn) -> syn::Result<TokenStream> {
+    let callable = wrapper.callable();
+    let mut conversions: Vec<Stmt> = Vec::new();
+
+    for arg in &mut item.sig.inputs {
+        let FnArg::Typed(arg) = arg else {
+            continue;
+        };
+        let Some(index) = arg.attrs.iter().position(|a| a.path().is_ident("wrap")) else {
+            continue;
+        };
+        let inner: Type = arg.attrs.remove(index).parse_args()?;
+
+        let Pat::Ident(pat) = &mut *arg.pat else {
+            return Err(Error::new(
+                arg.pat.span(),
+                "`#[wrap]` parameters must be bound to an identifier",
+            ));
+        };
+        if let Some((at, _)) = &pat.subpat {
+            return Err(Error::new(
+                at.span(),
+                "`#[wrap]` parameters can't be bound with `@` patterns",
+            ));
+        }
+        // The parameter only feeds the conversion, so `ref` and `mut` move to
+        // the binding that shadows it.
+        let by_ref = pat.by_ref.take();
+        let mutability = pat.mutability.take();
+        let ident = &pat.ident;
+        let wrapped = &arg.ty;
+
+        let (unwrapped, conversion): (Type, _) = match &*arg.ty {
+            Type::Array(array) => {
+                let len = &array.len;
+                (parse_quote!([#inner; #len]), quote!(#ident.map(#callable)))
+            }
+            ty => (
+                replace_item_type(ty, inner)?,
+                quote!(::core::iter::Iterator::collect(
+                    ::core::iter::Iterator::map(::core::iter::IntoIterator::into_iter(#ident), #callable)
+                )),
+            ),
+        };
+
+        conversions.push(parse_quote!(let #by_ref #mutability #ident: #wrapped = #conversion;));
+        *arg.ty = unwrapped;
+    }
+
+    item.block.stmts.splice(0..0, conversions);
+    Ok(item.into_token_stream())
+}
+
+/// Replaces the single type argument of a collection type such as `Vec<W>`.
+fn replace_item_type(ty: &Type, inner: Type) -> syn::Result<Type> {
+    let error = || {
+        Error::new(
+            ty.span(),
+            "`#[wrap]` parameters must be arrays or collections with a single type parameter",
+        )
+    };
+
+    let Type::Path(path) = ty else {
+        return Err(error());
+    };
+    let mut path = path.clone();
+    let segment = path.path.segments.last_mut().ok_or_else(error)?;
+    let PathArguments::AngleBracketed(args) = &mut segment.arguments else {
+        return Err(error());
+    };
+    let mut types = args.args.iter_mut().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    match (types.next(), types.next()) {
+        (Some(item), None) => *item = inner,
+        _ => return Err(error()),
+    }
+
+    Ok(Type::Path(path))
+}
@@ -0,0 +1,225 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use std::collections::HashMap;
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Error, Expr, ExprLit, ExprUnary, Ident, Lit, Path, Token, UnOp,
+};
+
+/// The function, object, or macro that every element is wrapped with.
+pub enum Wrapper {
+    /// A macro invocation such as `vec!` or `my_mod::my_macro!`.
+    Macro(Path),
+    /// A path, called directly for every element so generic functions are
+    /// instantiated separately for each one.
+    Path(Expr),
+    /// Any other expression, evaluated once and then called for every element.
+    Expr(Expr),
+}
+
+impl Wrapper {
+    /// Returns the tokens that wrap a single element, spanned to that element
+    /// so type errors point at the offending value.
+    pub fn wrap(&self, element: &Expr) -> TokenStream {
+        let span = element.span();
+        match self {
+            Wrapper::Macro(path) => quote_spanned!(span=> #path!(#element)),
+            Wrapper::Path(path) => quote_spanned!(span=> #path(#element)),
+            Wrapper::Expr(_) => {
+                let binding = Self::binding();
+                quote_spanned!(span=> #binding(#element))
+            }
+        }
+    }
+
+    /// Wraps `body` in a block that evaluates the wrapper expression once, if
+    /// it needs to be.
+    pub fn bind(&self, body: TokenStream) -> TokenStream {
+        match self {
+            Wrapper::Expr(expr) => {
+                let binding = Self::binding();
+                quote!({
+                    let #binding = #expr;
+                    #body
+                })
+            }
+            _ => body,
+        }
+    }
+
+    /// Returns an expression that can be called with a single element, for
+    /// use with adapters such as `Iterator::map`.
+    pub fn callable(&self) -> TokenStream {
+        match self {
+            Wrapper::Macro(path) => quote!(|value| #path!(value)),
+            Wrapper::Path(expr) | Wrapper::Expr(expr) => quote!(#expr),
+        }
+    }
+
+    fn binding() -> Ident {
+        Ident::new("__wrapper", Span::mixed_site())
+    }
+}
+
+impl Parse for Wrapper {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        if fork.parse::<Path>().is_ok() && fork.peek(Token![!]) {
+            let path = input.parse()?;
+            input.parse::<Token![!]>()?;
+            return Ok(Wrapper::Macro(path));
+        }
+
+        match input.parse()? {
+            expr @ Expr::Path(_) => Ok(Wrapper::Path(expr)),
+            expr => Ok(Wrapper::Expr(expr)),
+        }
+    }
+}
+
+/// Input of the `wrapped!` macro: `wrapper; element, ...`, optionally
+/// prefixed with `#unique`.
+pub struct Wrapped {
+    pub wrapper: Wrapper,
+    pub elements: Punctuated<Expr, Token![,]>,
+}
+
+/// Lists longer than this are written into an uninitialized array in chunks,
+/// since type checking one huge array literal scales poorly and building it
+/// needs a temporary as large as the whole array.
+const CHUNKED_THRESHOLD: usize = 1024;
+
+/// Number of elements written at a time by chunked lists, which bounds the
+/// size of the temporaries while keeping compile times close to those of a
+/// single chunk.
+const CHUNK_LEN: usize = 16;
+
+impl Wrapped {
+    pub fn expand(&self) -> TokenStream {
+        let elements: Vec<_> = self.elements.iter().map(|e| self.wrapper.wrap(e)).collect();
+        if elements.len() <= CHUNKED_THRESHOLD {
+            return self.wrapper.bind(quote!([#(#elements),*]));
+        }
+
+        let len = elements.len();
+        let array = Ident::new("__chunks", Span::mixed_site());
+        let chunks = elements.chunks(CHUNK_LEN);
+        self.wrapper.bind(quote!({
+            let mut #array = ::wrapped_list::__private::ChunkedArray::<_, #len>::new();
+            #(#array.push([#(#chunks),*]);)*
+            #array.finish()
+        }))
+    }
+}
+
+impl Parse for Wrapped {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let unique = input.peek(Token![#]);
+        if unique {
+            input.parse::<Token![#]>()?;
+            let flag: Ident = input.parse()?;
+            if flag != "unique" {
+                return Err(Error::new(flag.span(), "expected `unique`"));
+            }
+        }
+
+        let wrapper = input.parse()?;
+
+        if input.peek(Token![;]) {
+            input.parse::<Token![;]>()?;
+        } else if input.peek(Token![=>]) {
+            input.parse::<Token![=>]>()?;
+        } else {
+            return Err(input.error("expected `;` or `=>` after the wrapper"));
+        }
+
+        let elements = Punctuated::parse_terminated(input)?;
+        if unique {
+            check_unique(&elements, "element")?;
+        }
+
+        Ok(Wrapped { wrapper, elements })
+    }
+}
+
+/// Fails if two literal elements have the same value, naming them `what` in
+/// the error. Elements that aren't literals are never considered duplicates.
+pub fn check_unique(elements: &Punctuated<Expr, Token![,]>, what: &str) -> syn::Result<()> {
+    let mut seen = HashMap::new();
+    for element in elements {
+        let Some(key) = literal_key(element) else {
+            continue;
+        };
+        let span = ungroup(element).span();
+        if let Some(first) = seen.insert(key, span) {
+            let mut error = Error::new(span, format!("duplicate {what}"));
+            error.combine(Error::new(first, "the same value is first given here"));
+            return Err(error);
+        }
+    }
+    Ok(())
+}
+
+/// Returns the expression inside the invisible groups that `macro_rules!`
+/// fragments are passed in, whose spans cover the whole invocation.
+fn ungroup(mut element: &Expr) -> &Expr {
+    while let Expr::Group(group) = element {
+        element = &group.expr;
+    }
+    element
+}
+
+/// Returns a key identifying the value of a literal element, so literals
+/// written differently, such as `10` and `0xa`, or `1.0` and `1e0`, still
+/// compare equal.
+fn literal_key(element: &Expr) -> Option<String> {
+    numeric_key(element, false).or_else(|| match element {
+        Expr::Lit(ExprLit { lit, .. }) => Some(match lit {
+            Lit::Str(lit) => format!("str {:?}", lit.value()),
+            Lit::ByteStr(lit) => format!("bytes {:?}", lit.value()),
+            Lit::CStr(lit) => format!("cstr {:?}", lit.value()),
+            Lit::Byte(lit) => format!("byte {}", lit.value()),
+            Lit::Char(lit) => format!("char {:?}", lit.value()),
+            Lit::Bool(lit) => format!("bool {}", lit.value),
+            _ => return None,
+        }),
+        Expr::Group(group) => literal_key(&group.expr),
+        Expr::Paren(paren) => literal_key(&paren.expr),
+        _ => None,
+    })
+}
+
+/// Returns the key of a possibly negated integer or float literal, with `-0`
+/// and `0` sharing a key since they are the same value.
+fn numeric_key(element: &Expr, negative: bool) -> Option<String> {
+    match element {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => Some(match lit.base10_parse::<u128>() {
+            Ok(0) => "int 0".to_owned(),
+            Ok(value) if negative => format!("int -{value}"),
+            Ok(value) => format!("int {value}"),
+            Err(_) => return None,
+        }),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit),
+            ..
+        }) => {
+            let value = lit.base10_parse::<f64>().ok()?;
+            let value = if negative { -value } else { value };
+            // `-0.0 == 0.0`, but the two have different bits.
+            let value = if value == 0.0 { 0.0 } else { value };
+            Some(format!("float {:#x}", value.to_bits()))
+        }
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => numeric_key(expr, !negative),
+        Expr::Group(group) => numeric_key(&group.expr, negative),
+        Expr::Paren(paren) => numeric_key(&paren.expr, negative),
+        _ => None,
+    }
+}
@@ -0,0 +1,32 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{spanned::Spanned, Error, Expr, Item};
+
+use crate::wrapped::Wrapper;
+
+/// Rewrites the array literal initializer of a `static` or `const` item so
+/// that every element is wrapped.
+pub fn expand(wrapper: Wrapper, mut item: Item) -> syn::Result<TokenStream> {
+    let expr = match &mut item {
+        Item::Static(item) => &mut item.expr,
+        Item::Const(item) => &mut item.expr,
+        item => {
+            return Err(Error::new(
+                item.span(),
+                "`wrapped_array` can only be applied to `static` and `const` items",
+            ))
+        }
+    };
+
+    let Expr::Array(array) = &**expr else {
+        return Err(Error::new(
+            expr.span(),
+            "expected an array literal initializer",
+        ));
+    };
+
+    let elements = array.elems.iter().map(|e| wrapper.wrap(e));
+    **expr = syn::parse2(wrapper.bind(quote!([#(#elements),*])))?;
+
+    Ok(item.into_token_stream())
+}
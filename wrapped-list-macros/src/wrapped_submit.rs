@@ -0,0 +1,56 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    Error, Item, Path, StaticMutability, Token,
+};
+
+use crate::wrapped::Wrapper;
+
+/// Arguments of the `wrapped_submit` attribute: `REGISTRY, wrapper`.
+pub struct Submit {
+    pub registry: Path,
+    pub wrapper: Wrapper,
+}
+
+impl Parse for Submit {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let registry = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let wrapper = input.parse()?;
+        Ok(Submit { registry, wrapper })
+    }
+}
+
+impl Submit {
+    /// Keeps the `static` item as written and adds an entry to the registry
+    /// that wraps a value built from the same initializer.
+    pub fn expand(&self, item: Item) -> syn::Result<TokenStream> {
+        let Item::Static(item) = item else {
+            return Err(Error::new(
+                item.span(),
+                "`wrapped_submit` can only be applied to `static` items",
+            ));
+        };
+        if let StaticMutability::Mut(token) = item.mutability {
+            return Err(Error::new(
+                token.span(),
+                "`wrapped_submit` can't be applied to `static mut` items",
+            ));
+        }
+
+        let registry = &self.registry;
+        let element = self.wrapper.bind(self.wrapper.wrap(&item.expr));
+        let item = item.into_token_stream();
+        Ok(quote! {
+            #item
+
+            const _: () = {
+                #[::wrapped_list::__private::linkme::distributed_slice(#registry)]
+                #[linkme(crate = ::wrapped_list::__private::linkme)]
+                static ENTRY: #registry = || #element;
+            };
+        })
+    }
+}